@@ -1,29 +1,13 @@
 //! Naive clone of the `pidof` utility
 
+use std::collections::BTreeSet;
 use std::env;
 use std::ffi::OsStr;
 use std::io;
 
 use tokio::stream::StreamExt as _;
 
-use heim::{
-    process::{self, Process, ProcessResult},
-    Result,
-};
-
-async fn compare(process: ProcessResult<Process>, needle: &str) -> Option<process::Pid> {
-    let process = process.ok()?;
-    if needle == process.name().await.ok()? {
-        return Some(process.pid());
-    }
-
-    let command = process.command().await.ok()?;
-    if Some(&OsStr::new(needle)) == command.into_iter().next().as_ref() {
-        return Some(process.pid());
-    }
-
-    None
-}
+use heim::{process, Result};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -36,14 +20,35 @@ async fn main() -> Result<()> {
         }
     };
 
-    let processes = process::processes();
+    let mut pids = BTreeSet::new();
+
+    let processes = process::find().name(needle.clone()).processes();
     tokio::pin!(processes);
     while let Some(process) = processes.next().await {
-        if let Some(pid) = compare(process, &needle).await {
-            print!("{} ", pid);
+        if let Ok(process) = process {
+            let _ = pids.insert(process.pid());
         }
     }
 
+    // Process might be also matched by the first argument of its command line
+    let processes = process::find().command(needle.clone()).processes();
+    tokio::pin!(processes);
+    while let Some(process) = processes.next().await {
+        let process = match process {
+            Ok(process) => process,
+            Err(..) => continue,
+        };
+        if let Ok(command) = process.command().await {
+            if Some(&OsStr::new(&needle)) == command.into_iter().next().as_ref() {
+                let _ = pids.insert(process.pid());
+            }
+        }
+    }
+
+    for pid in pids {
+        print!("{} ", pid);
+    }
+
     println!();
 
     Ok(())
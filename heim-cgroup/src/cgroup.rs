@@ -0,0 +1,86 @@
+use std::fmt;
+
+use heim_common::prelude::*;
+use heim_common::Pid;
+
+use crate::{sys, Cpu, IoCounters, Memory, Pids};
+
+/// Control groups hierarchy version.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Version {
+    /// Legacy hierarchy, each controller is mounted separately.
+    V1,
+    /// Unified hierarchy.
+    V2,
+}
+
+/// Control group, which process belongs to.
+///
+/// Each resource is fetched from the hierarchy, where the corresponding controller
+/// is mounted, so in the "hybrid" setups some of them might come from the v1 hierarchies
+/// and others from the unified one.
+///
+/// All limits are "effective" ones, which means that the most restrictive limit
+/// set for this control group or any of its ancestors is returned.
+pub struct Cgroup(sys::Cgroup);
+
+wrap!(Cgroup, sys::Cgroup);
+
+impl Cgroup {
+    /// Returns hierarchy version used by this control group.
+    ///
+    /// [V1](./enum.Version.html#variant.V1) is returned if any controller is mounted
+    /// as a v1 hierarchy, even if the unified hierarchy exists too.
+    pub fn version(&self) -> Version {
+        self.as_ref().version()
+    }
+
+    /// Returns memory usage and limit for this control group.
+    pub async fn memory(&self) -> Result<Memory> {
+        self.as_ref().memory().await
+    }
+
+    /// Returns CPU usage, quota and throttling statistics for this control group.
+    pub async fn cpu(&self) -> Result<Cpu> {
+        self.as_ref().cpu().await
+    }
+
+    /// Returns I/O counters for each block device used by this control group.
+    pub async fn io_counters(&self) -> Result<Vec<IoCounters>> {
+        self.as_ref().io_counters().await
+    }
+
+    /// Returns amount of processes in this control group and its limit.
+    pub async fn pids(&self) -> Result<Pids> {
+        self.as_ref().pids().await
+    }
+}
+
+impl fmt::Debug for Cgroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Cgroup")
+            .field("version", &self.version())
+            .finish()
+    }
+}
+
+/// Returns control group of the current process.
+///
+/// ## Compatibility
+///
+/// For platforms other than Linux this function always returns an error.
+pub async fn cgroup() -> Result<Cgroup> {
+    sys::cgroup().await.map(Into::into)
+}
+
+/// Returns control group of the process with `pid` given.
+///
+/// Note that control group directories are resolved with the current process mounts,
+/// so the process from the different cgroup namespace might be not reachable.
+///
+/// ## Compatibility
+///
+/// For platforms other than Linux this function always returns an error.
+pub async fn pid_cgroup(pid: Pid) -> Result<Cgroup> {
+    sys::pid_cgroup(pid).await.map(Into::into)
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+use heim_common::units::Time;
+
+/// Control group CPU usage, quota and throttling statistics.
+pub struct Cpu {
+    pub(crate) usage: Time,
+    pub(crate) quota: Option<Time>,
+    pub(crate) period: Time,
+    pub(crate) periods: u64,
+    pub(crate) throttled_periods: u64,
+    pub(crate) throttled_time: Time,
+}
+
+impl Cpu {
+    /// Returns total CPU time consumed by all processes in the control group.
+    pub fn usage(&self) -> Time {
+        self.usage
+    }
+
+    /// Returns CPU time which control group is allowed to consume during each
+    /// [period](#method.period), `None` if it is not limited.
+    pub fn quota(&self) -> Option<Time> {
+        self.quota
+    }
+
+    /// Returns length of the CFS enforcement period.
+    pub fn period(&self) -> Time {
+        self.period
+    }
+
+    /// Returns amount of the enforcement periods elapsed.
+    pub fn periods(&self) -> u64 {
+        self.periods
+    }
+
+    /// Returns amount of the periods, when control group was throttled.
+    pub fn throttled_periods(&self) -> u64 {
+        self.throttled_periods
+    }
+
+    /// Returns total time control group was throttled for.
+    pub fn throttled_time(&self) -> Time {
+        self.throttled_time
+    }
+}
+
+impl fmt::Debug for Cpu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Cpu")
+            .field("usage", &self.usage())
+            .field("quota", &self.quota())
+            .field("period", &self.period())
+            .field("periods", &self.periods())
+            .field("throttled_periods", &self.throttled_periods())
+            .field("throttled_time", &self.throttled_time())
+            .finish()
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+use heim_common::units::Information;
+
+/// Control group I/O counters for one block device.
+#[derive(Default)]
+pub struct IoCounters {
+    pub(crate) major: u32,
+    pub(crate) minor: u32,
+    pub(crate) read_count: u64,
+    pub(crate) write_count: u64,
+    pub(crate) read_bytes: Information,
+    pub(crate) write_bytes: Information,
+}
+
+impl IoCounters {
+    /// Returns block device major number.
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    /// Returns block device minor number.
+    pub fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    /// Returns number of read operations.
+    pub fn read_count(&self) -> u64 {
+        self.read_count
+    }
+
+    /// Returns number of write operations.
+    pub fn write_count(&self) -> u64 {
+        self.write_count
+    }
+
+    /// Returns number of read bytes.
+    pub fn read_bytes(&self) -> Information {
+        self.read_bytes
+    }
+
+    /// Returns number of written bytes.
+    pub fn write_bytes(&self) -> Information {
+        self.write_bytes
+    }
+}
+
+impl fmt::Debug for IoCounters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IoCounters")
+            .field("major", &self.major())
+            .field("minor", &self.minor())
+            .field("read_count", &self.read_count())
+            .field("write_count", &self.write_count())
+            .field("read_bytes", &self.read_bytes())
+            .field("write_bytes", &self.write_bytes())
+            .finish()
+    }
+}
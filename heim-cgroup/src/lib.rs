@@ -0,0 +1,54 @@
+//! Linux control groups resource accounting.
+//!
+//! Processes running in containers are usually limited by the control groups
+//! they belong to, while the system-wide information (ex. `heim_memory::memory()`)
+//! still describes the host resources.
+//! This crate provides the limits and usage for the [cgroup] of any process,
+//! which are reflecting what the process workload is actually able to use.
+//!
+//! Both v1 and v2 cgroup hierarchies are supported, including the "hybrid" setups,
+//! where different controllers are mounted in different hierarchies.
+//!
+//! ## Compatibility
+//!
+//! Control groups are available for Linux only,
+//! for other platforms [cgroup] functions are always returning an error.
+//!
+//! [cgroup]: ./fn.cgroup.html
+
+#![doc(html_root_url = "https://docs.rs/heim-cgroup/0.1.0-alpha.1")]
+#![deny(
+    unused,
+    unused_imports,
+    unused_features,
+    bare_trait_objects,
+    future_incompatible,
+    missing_debug_implementations,
+    missing_docs,
+    nonstandard_style,
+    dead_code,
+    deprecated,
+    intra_doc_link_resolution_failure
+)]
+#![warn(
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_extern_crates,
+    unused_import_braces,
+    unused_results
+)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod sys;
+
+mod cgroup;
+mod cpu;
+mod io;
+mod memory;
+mod pids;
+
+pub use self::cgroup::*;
+pub use self::cpu::*;
+pub use self::io::*;
+pub use self::memory::*;
+pub use self::pids::*;
@@ -0,0 +1,33 @@
+use std::fmt;
+
+use heim_common::units::Information;
+
+/// Control group memory usage and limit.
+pub struct Memory {
+    pub(crate) usage: Option<Information>,
+    pub(crate) limit: Option<Information>,
+}
+
+impl Memory {
+    /// Returns the amount of memory currently used by the control group,
+    /// including the page cache.
+    ///
+    /// `None` is returned if usage is not accounted, as for the cgroup v2 root group.
+    pub fn usage(&self) -> Option<Information> {
+        self.usage
+    }
+
+    /// Returns memory limit, `None` if memory is not limited.
+    pub fn limit(&self) -> Option<Information> {
+        self.limit
+    }
+}
+
+impl fmt::Debug for Memory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Memory")
+            .field("usage", &self.usage())
+            .field("limit", &self.limit())
+            .finish()
+    }
+}
@@ -0,0 +1,30 @@
+use std::fmt;
+
+/// Control group processes amount and limit.
+pub struct Pids {
+    pub(crate) current: Option<u64>,
+    pub(crate) limit: Option<u64>,
+}
+
+impl Pids {
+    /// Returns the amount of processes and threads currently in the control group.
+    ///
+    /// `None` is returned if it is not accounted, as for the cgroup v2 root group.
+    pub fn current(&self) -> Option<u64> {
+        self.current
+    }
+
+    /// Returns processes amount limit, `None` if it is not limited.
+    pub fn limit(&self) -> Option<u64> {
+        self.limit
+    }
+}
+
+impl fmt::Debug for Pids {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Pids")
+            .field("current", &self.current())
+            .field("limit", &self.limit())
+            .finish()
+    }
+}
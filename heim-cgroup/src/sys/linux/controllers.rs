@@ -0,0 +1,391 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::path::Path;
+
+use heim_common::prelude::*;
+use heim_common::units::{information, time, Information, Time};
+use heim_common::utils::iter::{ParseIterator, TryIterator};
+use heim_runtime as rt;
+
+use super::hierarchy::Hierarchy;
+use super::Cgroup;
+use crate::{Cpu, IoCounters, Memory, Pids, Version};
+
+/// v1 hierarchies are reporting "unlimited" memory as a maximum signed value
+/// rounded down to the page size, so any value above this one is considered as unlimited.
+const V1_UNLIMITED: u64 = 1 << 62;
+
+/// Default CFS period, used when it can't be read from the hierarchy.
+const DEFAULT_PERIOD_US: u64 = 100_000;
+
+async fn read(path: &Path) -> Result<String> {
+    rt::fs::read_to_string(path).await.map_err(Error::from)
+}
+
+/// Reads file contents, returning `None` if file is missing,
+/// which is the case for the limits of the root control group.
+async fn read_optional(path: &Path) -> Result<Option<String>> {
+    match rt::fs::read_to_string(path).await {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn read_value(path: &Path) -> Result<u64> {
+    read(path)
+        .await?
+        .trim()
+        .parse()
+        .map_err(|e| Error::from(e).with_file(path))
+}
+
+async fn read_optional_value(path: &Path) -> Result<Option<u64>> {
+    match read_optional(path).await? {
+        Some(contents) => contents
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|e| Error::from(e).with_file(path)),
+        None => Ok(None),
+    }
+}
+
+/// Parses limit value, where `"max"` (v2) and `"-1"` (v1) are standing for "unlimited".
+fn parse_limit(value: &str) -> Result<Option<u64>> {
+    match value.trim() {
+        "max" => Ok(None),
+        value if value.starts_with('-') => Ok(None),
+        value => match value.parse::<u64>()? {
+            value if value >= V1_UNLIMITED => Ok(None),
+            value => Ok(Some(value)),
+        },
+    }
+}
+
+/// Parses "flat keyed" files like `cpu.stat`.
+fn parse_keyed(contents: &str) -> Result<HashMap<&str, u64>> {
+    let mut values = HashMap::new();
+    for line in contents.lines() {
+        let mut parts = line.split_ascii_whitespace();
+        let key = parts.try_next()?;
+        let _ = values.insert(key, parts.try_parse_next()?);
+    }
+
+    Ok(values)
+}
+
+/// Returns the most restrictive limit from the `file` of control group and all its ancestors.
+async fn effective_limit(hierarchy: &Hierarchy, file: &str) -> Result<Option<u64>> {
+    let mut result: Option<u64> = None;
+
+    for dir in hierarchy.ancestors() {
+        let path = dir.join(file);
+        let limit = match read_optional(&path).await? {
+            Some(contents) => parse_limit(&contents).map_err(|e| e.with_file(path))?,
+            None => continue,
+        };
+        if let Some(limit) = limit {
+            result = Some(result.map_or(limit, |result| result.min(limit)));
+        }
+    }
+
+    Ok(result)
+}
+
+/// Returns the most restrictive CPU quota and its period in microseconds.
+///
+/// Quotas from different levels are compared as a quota to period ratios,
+/// since their periods might differ.
+async fn effective_quota(version: Version, hierarchy: &Hierarchy) -> Result<(Option<u64>, u64)> {
+    let mut quota: Option<(u64, u64)> = None;
+    let mut leaf_period = None;
+
+    for dir in hierarchy.ancestors() {
+        let (level_quota, period) = match version {
+            Version::V1 => {
+                let period = match read_optional(&dir.join("cpu.cfs_period_us")).await? {
+                    Some(contents) => contents.trim().parse::<u64>()?,
+                    None => continue,
+                };
+                let path = dir.join("cpu.cfs_quota_us");
+                let quota = parse_limit(&read(&path).await?).map_err(|e| e.with_file(path))?;
+
+                (quota, period)
+            }
+            // `cpu.max` is formatted as `"$MAX $PERIOD"`
+            Version::V2 => {
+                let path = dir.join("cpu.max");
+                let contents = match read_optional(&path).await? {
+                    Some(contents) => contents,
+                    None => continue,
+                };
+                let mut parts = contents.split_ascii_whitespace();
+                let quota = parse_limit(parts.try_next()?).map_err(|e| e.with_file(&path))?;
+                let period = parts
+                    .try_parse_next::<u64, _>()
+                    .map_err(|e| e.with_file(&path))?;
+
+                (quota, period)
+            }
+        };
+
+        if leaf_period.is_none() {
+            leaf_period = Some(period);
+        }
+        if let Some(level_quota) = level_quota {
+            let is_stricter = match quota {
+                // Same as `level_quota / period < quota / quota_period`
+                Some((quota, quota_period)) => {
+                    u128::from(level_quota) * u128::from(quota_period)
+                        < u128::from(quota) * u128::from(period)
+                }
+                None => true,
+            };
+            if is_stricter {
+                quota = Some((level_quota, period));
+            }
+        }
+    }
+
+    match quota {
+        Some((quota, period)) => Ok((Some(quota), period)),
+        None => Ok((None, leaf_period.unwrap_or(DEFAULT_PERIOD_US))),
+    }
+}
+
+fn microseconds(value: u64) -> Time {
+    Time::new::<time::microsecond>(value as f64)
+}
+
+fn nanoseconds(value: u64) -> Time {
+    Time::new::<time::nanosecond>(value as f64)
+}
+
+fn bytes(value: u64) -> Information {
+    Information::new::<information::byte>(value)
+}
+
+/// Parses `"8:0"`-like device numbers.
+fn parse_device(device: &str) -> Result<(u32, u32)> {
+    let mut parts = device.splitn(2, ':');
+
+    Ok((parts.try_parse_next()?, parts.try_parse_next()?))
+}
+
+/// Parses v2 `io.stat` file, formatted as `"8:0 rbytes=1459200 wbytes=314773504 rios=192 wios=353"`.
+fn parse_io_stat(contents: &str) -> Result<Vec<IoCounters>> {
+    let mut result = Vec::new();
+
+    for line in contents.lines() {
+        let mut parts = line.split_ascii_whitespace();
+        let (major, minor) = parse_device(parts.try_next()?)?;
+        let mut counters = IoCounters {
+            major,
+            minor,
+            ..IoCounters::default()
+        };
+
+        for part in parts {
+            let mut pair = part.splitn(2, '=');
+            let key = pair.try_next()?;
+            let value = pair.try_parse_next::<u64, _>()?;
+            match key {
+                "rbytes" => counters.read_bytes = bytes(value),
+                "wbytes" => counters.write_bytes = bytes(value),
+                "rios" => counters.read_count = value,
+                "wios" => counters.write_count = value,
+                _ => continue,
+            }
+        }
+
+        result.push(counters);
+    }
+
+    Ok(result)
+}
+
+/// Parses v1 `blkio.throttle.io_service_bytes` and `blkio.throttle.io_serviced` files,
+/// formatted as `"8:0 Read 1459200"` lines, followed by the `"Total 1774014"` line.
+fn parse_blkio_stat(bytes_contents: &str, ops_contents: &str) -> Result<Vec<IoCounters>> {
+    let mut devices = BTreeMap::<(u32, u32), IoCounters>::new();
+
+    for (contents, is_bytes) in &[(bytes_contents, true), (ops_contents, false)] {
+        for line in contents.lines() {
+            let mut parts = line.split_ascii_whitespace();
+            let device = parts.try_next()?;
+            if device == "Total" {
+                continue;
+            }
+            let (major, minor) = parse_device(device)?;
+            let operation = parts.try_next()?;
+            let value = parts.try_parse_next::<u64, _>()?;
+
+            let counters = devices.entry((major, minor)).or_insert_with(|| IoCounters {
+                major,
+                minor,
+                ..IoCounters::default()
+            });
+            match (operation, is_bytes) {
+                ("Read", true) => counters.read_bytes = bytes(value),
+                ("Write", true) => counters.write_bytes = bytes(value),
+                ("Read", false) => counters.read_count = value,
+                ("Write", false) => counters.write_count = value,
+                _ => continue,
+            }
+        }
+    }
+
+    Ok(devices.into_iter().map(|entry| entry.1).collect())
+}
+
+impl Cgroup {
+    pub async fn memory(&self) -> Result<Memory> {
+        let (version, hierarchy) = self.hierarchy("memory")?;
+        let (usage_file, limit_file) = match version {
+            Version::V1 => ("memory.usage_in_bytes", "memory.limit_in_bytes"),
+            Version::V2 => ("memory.current", "memory.max"),
+        };
+
+        // Root cgroup v2 group has no usage file
+        let usage = read_optional_value(&hierarchy.path().join(usage_file)).await?;
+        let limit = effective_limit(hierarchy, limit_file).await?;
+
+        Ok(Memory {
+            usage: usage.map(bytes),
+            limit: limit.map(bytes),
+        })
+    }
+
+    pub async fn cpu(&self) -> Result<Cpu> {
+        let (version, hierarchy) = self.hierarchy("cpu")?;
+        let (quota, period) = effective_quota(version, hierarchy).await?;
+
+        let path = hierarchy.path().join("cpu.stat");
+        let contents = read(&path).await?;
+        let stat = parse_keyed(&contents).map_err(|e| e.with_file(&path))?;
+        let stat = |key| stat.get(key).cloned().unwrap_or(0);
+
+        let (usage, throttled_time) = match version {
+            Version::V1 => {
+                // CPU usage is accounted by the separate `cpuacct` controller,
+                // which is usually co-mounted with the `cpu` one
+                let (_, cpuacct) = self.hierarchy("cpuacct")?;
+                let usage = read_value(&cpuacct.path().join("cpuacct.usage")).await?;
+
+                (nanoseconds(usage), nanoseconds(stat("throttled_time")))
+            }
+            Version::V2 => (
+                microseconds(stat("usage_usec")),
+                microseconds(stat("throttled_usec")),
+            ),
+        };
+
+        Ok(Cpu {
+            usage,
+            quota: quota.map(microseconds),
+            period: microseconds(period),
+            periods: stat("nr_periods"),
+            throttled_periods: stat("nr_throttled"),
+            throttled_time,
+        })
+    }
+
+    pub async fn io_counters(&self) -> Result<Vec<IoCounters>> {
+        match self.hierarchy("blkio")? {
+            (Version::V1, hierarchy) => {
+                let bytes_path = hierarchy.path().join("blkio.throttle.io_service_bytes");
+                let ops_path = hierarchy.path().join("blkio.throttle.io_serviced");
+                let bytes_contents = read_optional(&bytes_path).await?.unwrap_or_default();
+                let ops_contents = read_optional(&ops_path).await?.unwrap_or_default();
+
+                parse_blkio_stat(&bytes_contents, &ops_contents)
+                    .map_err(|e| e.with_file(bytes_path))
+            }
+            (Version::V2, hierarchy) => {
+                let path = hierarchy.path().join("io.stat");
+                let contents = read_optional(&path).await?.unwrap_or_default();
+
+                parse_io_stat(&contents).map_err(|e| e.with_file(path))
+            }
+        }
+    }
+
+    pub async fn pids(&self) -> Result<Pids> {
+        let (_, hierarchy) = self.hierarchy("pids")?;
+
+        // Root cgroup v2 group has no `pids.current` file
+        let current = read_optional_value(&hierarchy.path().join("pids.current")).await?;
+        let limit = effective_limit(hierarchy, "pids.max").await?;
+
+        Ok(Pids { current, limit })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::information;
+
+    use super::{parse_blkio_stat, parse_io_stat, parse_keyed, parse_limit};
+
+    #[test]
+    fn test_parse_limit() {
+        assert_eq!(parse_limit("max\n").unwrap(), None);
+        assert_eq!(parse_limit("-1\n").unwrap(), None);
+        assert_eq!(parse_limit("9223372036854771712\n").unwrap(), None);
+        assert_eq!(parse_limit("536870912\n").unwrap(), Some(536_870_912));
+        assert!(parse_limit("foo").is_err());
+    }
+
+    #[test]
+    fn test_parse_keyed() {
+        let stat = parse_keyed("nr_periods 12\nnr_throttled 3\nthrottled_time 4500\n").unwrap();
+
+        assert_eq!(stat["nr_periods"], 12);
+        assert_eq!(stat["throttled_time"], 4500);
+    }
+
+    #[test]
+    fn test_parse_io_stat() {
+        let contents = "\
+8:16 rbytes=1459200 wbytes=314773504 rios=192 wios=353 dbytes=0 dios=0
+8:0 rbytes=90430464 wbytes=299008000 rios=8950 wios=1252 dbytes=50331648 dios=3021
+";
+        let counters = parse_io_stat(contents).unwrap();
+
+        assert_eq!(counters.len(), 2);
+        assert_eq!((counters[0].major(), counters[0].minor()), (8, 16));
+        assert_eq!(
+            counters[0].write_bytes().get::<information::byte>(),
+            314_773_504
+        );
+        assert_eq!(counters[1].read_count(), 8950);
+    }
+
+    #[test]
+    fn test_parse_blkio_stat() {
+        let bytes = "\
+8:0 Read 1459200
+8:0 Write 314773504
+8:0 Sync 0
+8:0 Async 316232704
+8:0 Total 316232704
+Total 316232704
+";
+        let ops = "\
+8:0 Read 192
+8:0 Write 353
+8:0 Total 545
+Total 545
+";
+        let counters = parse_blkio_stat(bytes, ops).unwrap();
+
+        assert_eq!(counters.len(), 1);
+        assert_eq!(
+            counters[0].read_bytes().get::<information::byte>(),
+            1_459_200
+        );
+        assert_eq!(counters[0].read_count(), 192);
+        assert_eq!(counters[0].write_count(), 353);
+    }
+}
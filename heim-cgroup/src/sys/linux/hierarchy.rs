@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use heim_common::prelude::*;
+use heim_common::utils::iter::TryIterator;
+use heim_runtime as rt;
+
+/// Single `/proc/<pid>/cgroup` entry, see `cgroups(7)` for details.
+#[derive(Debug, Eq, PartialEq)]
+struct Membership {
+    /// Controllers bound to the hierarchy, empty for the unified one.
+    controllers: Vec<String>,
+    path: PathBuf,
+}
+
+fn parse_memberships(contents: &str) -> Result<Vec<Membership>> {
+    let mut memberships = Vec::new();
+
+    for line in contents.lines() {
+        let mut parts = line.splitn(3, ':');
+        let _id = parts.try_next()?;
+        let controllers = parts.try_next()?;
+        let path = parts.try_next()?;
+
+        memberships.push(Membership {
+            controllers: controllers
+                .split(',')
+                .filter(|controller| !controller.is_empty())
+                .map(ToString::to_string)
+                .collect(),
+            path: PathBuf::from(path),
+        });
+    }
+
+    Ok(memberships)
+}
+
+/// cgroup filesystem entry from the `/proc/self/mountinfo`, see `proc(5)` for details.
+#[derive(Debug, Eq, PartialEq)]
+struct Mount {
+    /// Path of the directory in the hierarchy, which forms the root of this mount.
+    root: PathBuf,
+    mountpoint: PathBuf,
+    unified: bool,
+    /// Super block options, v1 hierarchies are listing their controllers here.
+    options: Vec<String>,
+}
+
+fn parse_mounts(contents: &str) -> Result<Vec<Mount>> {
+    let mut mounts = Vec::new();
+
+    for line in contents.lines() {
+        // Optional fields are separated from the rest by a single hyphen
+        let mut halves = line.splitn(2, " - ");
+        let mut parts = halves.try_next()?.split_ascii_whitespace().skip(3);
+        let root = parts.try_next()?;
+        let mountpoint = parts.try_next()?;
+
+        let mut parts = halves.try_next()?.split_ascii_whitespace();
+        let unified = match parts.try_next()? {
+            "cgroup" => false,
+            "cgroup2" => true,
+            _ => continue,
+        };
+        let _source = parts.try_next()?;
+        let options = parts.try_next()?;
+
+        mounts.push(Mount {
+            root: PathBuf::from(root),
+            mountpoint: PathBuf::from(mountpoint),
+            unified,
+            options: options.split(',').map(ToString::to_string).collect(),
+        });
+    }
+
+    Ok(mounts)
+}
+
+/// Control group directory in the mounted hierarchy.
+#[derive(Debug, Clone)]
+pub struct Hierarchy {
+    mountpoint: PathBuf,
+    path: PathBuf,
+}
+
+impl Hierarchy {
+    fn resolve(mount: &Mount, path: &Path) -> Option<Hierarchy> {
+        // Control group might be outside of the mounted part of hierarchy,
+        // ex. if process belongs to the different cgroup namespace.
+        let relative = path.strip_prefix(&mount.root).ok()?;
+        let mountpoint = match mount.mountpoint.strip_prefix("/sys") {
+            Ok(mountpoint) => rt::fs::sys_path(mountpoint),
+            Err(..) => mount.mountpoint.clone(),
+        };
+        let path = mountpoint.join(relative);
+
+        Some(Hierarchy { mountpoint, path })
+    }
+
+    /// Returns control group directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns control group directory along with its ancestors up to the hierarchy root.
+    pub fn ancestors(&self) -> impl Iterator<Item = &Path> {
+        let mountpoint = &self.mountpoint;
+
+        self.path
+            .ancestors()
+            .take_while(move |path| path.starts_with(mountpoint))
+    }
+}
+
+/// Mounted hierarchies, which process belongs to.
+#[derive(Debug, Default)]
+pub struct Hierarchies {
+    /// v1 hierarchies indexed by the controller name.
+    pub legacy: HashMap<String, Hierarchy>,
+    pub unified: Option<Hierarchy>,
+}
+
+fn resolve(memberships: Vec<Membership>, mounts: &[Mount]) -> Hierarchies {
+    let mut hierarchies = Hierarchies::default();
+
+    for membership in memberships {
+        if membership.controllers.is_empty() {
+            hierarchies.unified = mounts
+                .iter()
+                .filter(|mount| mount.unified)
+                .filter_map(|mount| Hierarchy::resolve(mount, &membership.path))
+                .next();
+            continue;
+        }
+
+        let hierarchy = mounts
+            .iter()
+            .filter(|mount| !mount.unified)
+            .filter(|mount| {
+                membership
+                    .controllers
+                    .iter()
+                    .all(|controller| mount.options.contains(controller))
+            })
+            .filter_map(|mount| Hierarchy::resolve(mount, &membership.path))
+            .next();
+
+        if let Some(hierarchy) = hierarchy {
+            for controller in membership.controllers {
+                // Named hierarchies (ex. `name=systemd`) are not providing any resources
+                if !controller.starts_with("name=") {
+                    let _ = hierarchies.legacy.insert(controller, hierarchy.clone());
+                }
+            }
+        }
+    }
+
+    hierarchies
+}
+
+pub async fn hierarchies<T>(cgroup: T) -> Result<Hierarchies>
+where
+    T: AsRef<Path>,
+{
+    let memberships = rt::fs::read_to_string(rt::fs::proc_path(&cgroup)).await?;
+    let memberships =
+        parse_memberships(&memberships).map_err(|e| e.with_file(rt::fs::proc_path(&cgroup)))?;
+
+    // Control groups are resolved with the current process mounts,
+    // as these are the directories we are able to read.
+    let mounts = rt::fs::read_to_string(rt::fs::proc_path("self/mountinfo")).await?;
+    let mounts =
+        parse_mounts(&mounts).map_err(|e| e.with_file(rt::fs::proc_path("self/mountinfo")))?;
+
+    Ok(resolve(memberships, &mounts))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{parse_memberships, parse_mounts, resolve};
+
+    static HYBRID_CGROUP: &str = "12:pids:/docker/e4f1
+11:cpu,cpuacct:/docker/e4f1
+4:memory:/docker/e4f1
+1:name=systemd:/docker/e4f1
+0::/docker/e4f1
+";
+
+    static HYBRID_MOUNTINFO: &str = "\
+24 29 0:22 / /sys rw,nosuid,nodev,noexec,relatime shared:7 - sysfs sysfs rw
+32 24 0:28 / /sys/fs/cgroup ro,nosuid shared:9 - tmpfs tmpfs ro,mode=755
+33 32 0:29 / /sys/fs/cgroup/unified rw,nosuid shared:10 - cgroup2 cgroup2 rw,nsdelegate
+34 32 0:30 / /sys/fs/cgroup/systemd rw,nosuid shared:11 - cgroup cgroup rw,xattr,name=systemd
+38 32 0:34 / /sys/fs/cgroup/cpu,cpuacct rw,nosuid shared:16 - cgroup cgroup rw,cpu,cpuacct
+41 32 0:37 /docker /sys/fs/cgroup/memory rw,nosuid shared:19 - cgroup cgroup rw,memory
+";
+
+    #[test]
+    fn test_parse_memberships() {
+        let memberships = parse_memberships(HYBRID_CGROUP).unwrap();
+
+        assert_eq!(memberships.len(), 5);
+        assert_eq!(memberships[1].controllers, vec!["cpu", "cpuacct"]);
+        assert_eq!(memberships[1].path, Path::new("/docker/e4f1"));
+        assert!(memberships[4].controllers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_mounts() {
+        let mounts = parse_mounts(HYBRID_MOUNTINFO).unwrap();
+
+        assert_eq!(mounts.len(), 4);
+        assert!(mounts[0].unified);
+        assert_eq!(mounts[0].mountpoint, Path::new("/sys/fs/cgroup/unified"));
+        assert!(!mounts[2].unified);
+        assert!(mounts[2].options.contains(&"cpuacct".to_string()));
+        assert_eq!(mounts[3].root, Path::new("/docker"));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let memberships = parse_memberships(HYBRID_CGROUP).unwrap();
+        let mounts = parse_mounts(HYBRID_MOUNTINFO).unwrap();
+        let hierarchies = resolve(memberships, &mounts);
+
+        // `pids` controller is not mounted, named hierarchies are skipped
+        assert_eq!(hierarchies.legacy.len(), 3);
+        assert_eq!(
+            hierarchies.legacy["cpuacct"].path(),
+            Path::new("/sys/fs/cgroup/cpu,cpuacct/docker/e4f1")
+        );
+
+        let memory = &hierarchies.legacy["memory"];
+        assert_eq!(memory.path(), Path::new("/sys/fs/cgroup/memory/e4f1"));
+        assert_eq!(
+            memory.ancestors().collect::<Vec<_>>(),
+            vec![
+                Path::new("/sys/fs/cgroup/memory/e4f1"),
+                Path::new("/sys/fs/cgroup/memory")
+            ]
+        );
+
+        let unified = hierarchies.unified.unwrap();
+        assert_eq!(
+            unified.path(),
+            Path::new("/sys/fs/cgroup/unified/docker/e4f1")
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use std::io;
+
+use heim_common::prelude::*;
+use heim_common::Pid;
+
+use crate::Version;
+
+mod controllers;
+mod hierarchy;
+
+use self::hierarchy::{Hierarchies, Hierarchy};
+
+#[derive(Debug)]
+pub struct Cgroup {
+    hierarchies: Hierarchies,
+}
+
+impl Cgroup {
+    pub fn version(&self) -> Version {
+        if self.hierarchies.legacy.is_empty() && self.hierarchies.unified.is_some() {
+            Version::V2
+        } else {
+            Version::V1
+        }
+    }
+
+    /// Returns hierarchy where `controller` is mounted.
+    ///
+    /// Controllers bound to the v1 hierarchies are preferred over the unified one,
+    /// as the same controller can't be enabled for both of them at once.
+    fn hierarchy(&self, controller: &'static str) -> Result<(Version, &Hierarchy)> {
+        if let Some(hierarchy) = self.hierarchies.legacy.get(controller) {
+            return Ok((Version::V1, hierarchy));
+        }
+
+        match self.hierarchies.unified {
+            Some(ref hierarchy) => Ok((Version::V2, hierarchy)),
+            None => {
+                let inner = io::Error::from(io::ErrorKind::NotFound);
+                Err(Error::from(inner).with_message(format!(
+                    "cgroup controller \"{}\" is not mounted",
+                    controller
+                )))
+            }
+        }
+    }
+}
+
+pub async fn cgroup() -> Result<Cgroup> {
+    Ok(Cgroup {
+        hierarchies: hierarchy::hierarchies("self/cgroup").await?,
+    })
+}
+
+pub async fn pid_cgroup(pid: Pid) -> Result<Cgroup> {
+    Ok(Cgroup {
+        hierarchies: hierarchy::hierarchies(format!("{}/cgroup", pid)).await?,
+    })
+}
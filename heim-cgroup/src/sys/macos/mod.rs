@@ -0,0 +1,42 @@
+use std::io;
+
+use heim_common::prelude::*;
+use heim_common::Pid;
+
+use crate::{Cpu, IoCounters, Memory, Pids, Version};
+
+/// Control groups are not available for this platform,
+/// so this type can't be constructed at all.
+#[derive(Debug)]
+pub enum Cgroup {}
+
+impl Cgroup {
+    pub fn version(&self) -> Version {
+        match *self {}
+    }
+
+    pub async fn memory(&self) -> Result<Memory> {
+        match *self {}
+    }
+
+    pub async fn cpu(&self) -> Result<Cpu> {
+        match *self {}
+    }
+
+    pub async fn io_counters(&self) -> Result<Vec<IoCounters>> {
+        match *self {}
+    }
+
+    pub async fn pids(&self) -> Result<Pids> {
+        match *self {}
+    }
+}
+
+pub async fn cgroup() -> Result<Cgroup> {
+    Err(Error::from(io::Error::from(io::ErrorKind::Other))
+        .with_message("Control groups are supported on Linux only"))
+}
+
+pub async fn pid_cgroup(_pid: Pid) -> Result<Cgroup> {
+    cgroup().await
+}
@@ -0,0 +1,50 @@
+use heim_cgroup as cgroup;
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_cgroup() {
+    let cgroup = cgroup::cgroup().await.unwrap();
+
+    let _ = cgroup.version();
+    // Controllers might be not mounted or not available for the root control group
+    if let Ok(memory) = cgroup.memory().await {
+        let _ = memory.usage();
+        let _ = memory.limit();
+    }
+    if let Ok(cpu) = cgroup.cpu().await {
+        let _ = cpu.usage();
+        let _ = cpu.quota();
+        let _ = cpu.period();
+        let _ = cpu.periods();
+        let _ = cpu.throttled_periods();
+        let _ = cpu.throttled_time();
+    }
+    if let Ok(counters) = cgroup.io_counters().await {
+        for counter in counters {
+            let _ = counter.major();
+            let _ = counter.minor();
+            let _ = counter.read_count();
+            let _ = counter.write_count();
+            let _ = counter.read_bytes();
+            let _ = counter.write_bytes();
+        }
+    }
+    if let Ok(pids) = cgroup.pids().await {
+        let _ = pids.current();
+        let _ = pids.limit();
+    }
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_pid_cgroup() {
+    let cgroup = cgroup::pid_cgroup(1).await.unwrap();
+
+    let _ = cgroup.version();
+}
+
+#[heim_derive::test]
+#[cfg(not(target_os = "linux"))]
+async fn smoke_cgroup() {
+    assert!(cgroup::cgroup().await.is_err());
+}
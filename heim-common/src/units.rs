@@ -4,7 +4,7 @@
 
 pub use uom::si::f32::{Ratio, ThermodynamicTemperature};
 pub use uom::si::f64::Time;
-pub use uom::si::u64::{Frequency, Information};
+pub use uom::si::u64::{Frequency, Information, InformationRate};
 pub use uom::si::{
     frequency, information, information_rate, ratio, thermodynamic_temperature, time,
 };
@@ -7,6 +7,22 @@ pub async fn logical_count() -> Result<u64> {
     sys::logical_count().await
 }
 
+/// Returns an amount of logical CPUs, which current process is actually able to use.
+///
+/// Unlike [logical_count], which returns the amount of CPUs available for the whole system,
+/// this one is clamped by the process CPU affinity and by the CPU quota of the control group
+/// process belongs to, rounded up to the whole CPUs.
+///
+/// ## Compatibility
+///
+/// At the moment CPU affinity and quota are respected for Linux only,
+/// for other platforms this function returns the same value as [logical_count].
+///
+/// [logical_count]: ./fn.logical_count.html
+pub async fn cpu_count_effective() -> Result<u64> {
+    sys::cpu_count_effective().await
+}
+
 /// Returns an amount of physical CPUs.
 ///
 /// ## Returns
@@ -31,8 +31,10 @@ mod count;
 mod freq;
 mod stats;
 mod times;
+mod usage;
 
 pub use self::count::*;
 pub use self::freq::*;
 pub use self::stats::*;
 pub use self::times::*;
+pub use self::usage::*;
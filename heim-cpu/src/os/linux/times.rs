@@ -1,4 +1,4 @@
-use heim_common::units::Time;
+use heim_common::units::{Ratio, Time};
 
 /// Linux-specific extension for [CpuTime].
 ///
@@ -69,3 +69,46 @@ impl CpuTimeExt for crate::CpuTime {
         self.as_ref().guest_nice()
     }
 }
+
+/// Linux-specific extension for [CpuTimePercent].
+///
+/// [CpuTimePercent]: ../../struct.CpuTimePercent.html
+pub trait CpuTimePercentExt {
+    /// Returns share of time spent by niced (prioritized) processes executing in user mode.
+    fn nice(&self) -> Ratio;
+
+    /// Returns share of time spent waiting for I/O to complete.
+    fn io_wait(&self) -> Ratio;
+
+    /// Returns share of time spent for servicing hardware interrupts.
+    fn irq(&self) -> Ratio;
+
+    /// Returns share of time spent for servicing software interrupts.
+    fn soft_irq(&self) -> Ratio;
+
+    /// Returns share of time spent by other operating systems running in a virtualized environment.
+    fn steal(&self) -> Ratio;
+}
+
+#[cfg(target_os = "linux")]
+impl CpuTimePercentExt for crate::CpuTimePercent {
+    fn nice(&self) -> Ratio {
+        self.ratio(|time| time.nice())
+    }
+
+    fn io_wait(&self) -> Ratio {
+        self.ratio(|time| time.io_wait())
+    }
+
+    fn irq(&self) -> Ratio {
+        self.ratio(|time| time.irq())
+    }
+
+    fn soft_irq(&self) -> Ratio {
+        self.ratio(|time| time.soft_irq())
+    }
+
+    fn steal(&self) -> Ratio {
+        self.ratio(|time| time.steal())
+    }
+}
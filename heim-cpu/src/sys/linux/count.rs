@@ -1,5 +1,7 @@
+mod effective;
 mod logical;
 mod physical;
 
+pub use self::effective::*;
 pub use self::logical::*;
 pub use self::physical::*;
@@ -0,0 +1,50 @@
+use std::mem;
+
+use heim_common::prelude::*;
+use heim_common::units::time;
+
+use super::logical_count;
+
+fn affinity_count() -> Result<u64> {
+    let mut set = unsafe { mem::zeroed::<libc::cpu_set_t>() };
+    let result = unsafe { libc::sched_getaffinity(0, mem::size_of::<libc::cpu_set_t>(), &mut set) };
+
+    if result < 0 {
+        return Err(Error::last_os_error().with_ffi("sched_getaffinity"));
+    }
+
+    let count = (0..libc::CPU_SETSIZE as usize)
+        .filter(|cpu| unsafe { libc::CPU_ISSET(*cpu, &set) })
+        .count();
+
+    Ok(count as u64)
+}
+
+/// Returns CPU quota of the current process control group, rounded up to the whole CPUs.
+async fn quota_count() -> Result<Option<u64>> {
+    let cpu = heim_cgroup::cgroup().await?.cpu().await?;
+
+    let count = cpu.quota().map(|quota| {
+        let period = cpu.period().get::<time::microsecond>();
+        let count = (quota.get::<time::microsecond>() / period).ceil() as u64;
+        // Quota might be smaller than one period, but we are still able to use one CPU
+        count.max(1)
+    });
+
+    Ok(count)
+}
+
+pub async fn cpu_count_effective() -> Result<u64> {
+    let mut count = logical_count().await?;
+
+    if let Ok(affinity) = affinity_count() {
+        count = count.min(affinity);
+    }
+    // Control group might be not available at all (ex. cgroup filesystem is not mounted),
+    // in that case process is not limited by it.
+    if let Ok(Some(quota)) = quota_count().await {
+        count = count.min(quota);
+    }
+
+    Ok(count)
+}
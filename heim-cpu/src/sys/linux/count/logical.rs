@@ -12,7 +12,7 @@ fn sysconf() -> Result<u64> {
 }
 
 async fn cpuinfo() -> Result<u64> {
-    let mut lines = rt::fs::read_lines("/proc/cpuinfo").await?;
+    let mut lines = rt::fs::read_lines(rt::fs::proc_path("cpuinfo")).await?;
     let mut count = 0;
     while let Some(line) = lines.next().await {
         let line = line?;
@@ -27,7 +27,7 @@ async fn cpuinfo() -> Result<u64> {
 async fn stat() -> Result<u64> {
     // the first "cpu" line aggregates the numbers in all
     // of the other "cpuN" lines, hence skip the first item
-    let mut lines = rt::fs::read_lines("/proc/stat").await?.skip(1);
+    let mut lines = rt::fs::read_lines(rt::fs::proc_path("stat")).await?.skip(1);
 
     let mut count = 0;
     while let Some(line) = lines.next().await {
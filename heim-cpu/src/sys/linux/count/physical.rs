@@ -8,7 +8,7 @@ use heim_runtime as rt;
 
 async fn topology() -> Result<u64> {
     let mut acc = HashSet::<u64>::new();
-    let mut entries = rt::fs::read_dir("/sys/devices/system/cpu/").await?;
+    let mut entries = rt::fs::read_dir(rt::fs::sys_path("devices/system/cpu/")).await?;
     while let Some(entry) = entries.next().await {
         let entry = entry?;
 
@@ -62,7 +62,7 @@ fn parse_line(line: &str) -> Result<u64> {
 async fn cpu_info() -> Result<Option<u64>> {
     let mut acc = Collector::default();
 
-    let mut lines = rt::fs::read_lines("/proc/cpuinfo").await?;
+    let mut lines = rt::fs::read_lines(rt::fs::proc_path("cpuinfo")).await?;
     while let Some(line) = lines.next().await {
         match &line? {
             l if l.starts_with("physical id") => {
@@ -96,7 +96,7 @@ pub fn frequencies() -> impl Stream<Item = Result<CpuFrequency>> {
 
     // TODO: https://github.com/giampaolo/psutil/issues/1269
 
-    rt::fs::read_dir("/sys/devices/system/cpu/")
+    rt::fs::read_dir(rt::fs::sys_path("devices/system/cpu/"))
         .try_flatten_stream()
         .map_err(Error::from)
         .try_filter_map(|entry| async move {
@@ -57,5 +57,5 @@ impl FromStr for CpuStats {
 }
 
 pub async fn stats() -> Result<CpuStats> {
-    rt::fs::read_into("/proc/stat").await
+    rt::fs::read_into(rt::fs::proc_path("stat")).await
 }
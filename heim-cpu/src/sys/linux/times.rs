@@ -5,6 +5,8 @@ use heim_common::sys::unix::CLOCK_TICKS;
 use heim_common::units::{time, Time};
 use heim_runtime as rt;
 
+use crate::sys::delta;
+
 #[derive(Debug, Default)]
 pub struct CpuTime {
     user: Time,
@@ -50,6 +52,42 @@ impl CpuTime {
     pub fn guest_nice(&self) -> Option<Time> {
         self.guest_nice
     }
+
+    /// Returns time spent in all modes.
+    ///
+    /// Guest time is already accounted in the user and nice times.
+    pub fn total(&self) -> Time {
+        self.user
+            + self.nice
+            + self.system
+            + self.idle
+            + self.io_wait
+            + self.irq
+            + self.soft_irq
+            + self.steal
+    }
+
+    /// Returns time spent in each mode since the `previous` measurement.
+    pub fn delta(&self, previous: &CpuTime) -> CpuTime {
+        CpuTime {
+            user: delta(self.user, previous.user),
+            nice: delta(self.nice, previous.nice),
+            system: delta(self.system, previous.system),
+            idle: delta(self.idle, previous.idle),
+            io_wait: delta(self.io_wait, previous.io_wait),
+            irq: delta(self.irq, previous.irq),
+            soft_irq: delta(self.soft_irq, previous.soft_irq),
+            steal: delta(self.steal, previous.steal),
+            guest: match (self.guest, previous.guest) {
+                (Some(current), Some(previous)) => Some(delta(current, previous)),
+                (current, _) => current,
+            },
+            guest_nice: match (self.guest_nice, previous.guest_nice) {
+                (Some(current), Some(previous)) => Some(delta(current, previous)),
+                (current, _) => current,
+            },
+        }
+    }
 }
 
 impl FromStr for CpuTime {
@@ -88,7 +126,7 @@ impl FromStr for CpuTime {
 
 pub async fn time() -> Result<CpuTime> {
     // cumulative time is always the first line
-    let mut lines = rt::fs::read_lines_into::<_, CpuTime, _>("/proc/stat").await?;
+    let mut lines = rt::fs::read_lines_into::<_, CpuTime, _>(rt::fs::proc_path("stat")).await?;
     match lines.next().await {
         Some(line) => line,
         None => Err(Error::missing_key("cumulative time line", "/proc/stat")),
@@ -96,7 +134,7 @@ pub async fn time() -> Result<CpuTime> {
 }
 
 pub fn times() -> impl Stream<Item = Result<CpuTime>> {
-    rt::fs::read_lines("/proc/stat")
+    rt::fs::read_lines(rt::fs::proc_path("stat"))
         .try_flatten_stream()
         .skip(1)
         .try_filter(|line| future::ready(line.starts_with("cpu")))
@@ -5,6 +5,10 @@ pub async fn logical_count() -> Result<u64> {
     unsafe { sysctl::sysctlbyname(b"hw.logicalcpu\0") }
 }
 
+pub async fn cpu_count_effective() -> Result<u64> {
+    logical_count().await
+}
+
 pub async fn physical_count() -> Result<Option<u64>> {
     unsafe { sysctl::sysctlbyname(b"hw.physicalcpu\0").map(Some) }
 }
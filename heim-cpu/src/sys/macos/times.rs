@@ -3,8 +3,9 @@ use heim_common::sys::unix::CLOCK_TICKS;
 use heim_common::units::{time, Time};
 
 use super::bindings;
+use crate::sys::delta;
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct CpuTime {
     user: Time,
     nice: Time,
@@ -28,6 +29,21 @@ impl CpuTime {
     pub fn idle(&self) -> Time {
         self.idle
     }
+
+    /// Returns time spent in all modes.
+    pub fn total(&self) -> Time {
+        self.user + self.nice + self.system + self.idle
+    }
+
+    /// Returns time spent in each mode since the `previous` measurement.
+    pub fn delta(&self, previous: &CpuTime) -> CpuTime {
+        CpuTime {
+            user: delta(self.user, previous.user),
+            nice: delta(self.nice, previous.nice),
+            system: delta(self.system, previous.system),
+            idle: delta(self.idle, previous.idle),
+        }
+    }
 }
 
 impl From<bindings::host_cpu_load_info> for CpuTime {
@@ -15,3 +15,17 @@ cfg_if::cfg_if! {
         compile_error!("Unsupported target OS");
     }
 }
+
+use heim_common::units::Time;
+
+/// Returns difference between the `current` and `previous` time counters.
+///
+/// Some counters, ex. I/O wait time at Linux, might decrease between measurements,
+/// in that case zero time is returned.
+pub(crate) fn delta(current: Time, previous: Time) -> Time {
+    if current > previous {
+        current - previous
+    } else {
+        Time::default()
+    }
+}
@@ -15,6 +15,10 @@ pub async fn logical_count() -> Result<u64> {
     }
 }
 
+pub async fn cpu_count_effective() -> Result<u64> {
+    logical_count().await
+}
+
 pub async fn physical_count() -> Result<Option<u64>> {
     let processors = LogicalProcessors::get()?;
     let count = processors
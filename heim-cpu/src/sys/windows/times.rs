@@ -6,7 +6,9 @@ use heim_common::prelude::*;
 use heim_common::sys::IntoTime as _;
 use heim_common::units::Time;
 
-#[derive(Debug)]
+use crate::sys::delta;
+
+#[derive(Debug, Default)]
 pub struct CpuTime {
     user: Time,
     system: Time,
@@ -25,6 +27,20 @@ impl CpuTime {
     pub fn idle(&self) -> Time {
         self.idle
     }
+
+    /// Returns time spent in all modes.
+    pub fn total(&self) -> Time {
+        self.user + self.system + self.idle
+    }
+
+    /// Returns time spent in each mode since the `previous` measurement.
+    pub fn delta(&self, previous: &CpuTime) -> CpuTime {
+        CpuTime {
+            user: delta(self.user, previous.user),
+            system: delta(self.system, previous.system),
+            idle: delta(self.idle, previous.idle),
+        }
+    }
 }
 
 // https://docs.microsoft.com/en-us/windows/desktop/api/processthreadsapi/nf-processthreadsapi-getsystemtimes
@@ -0,0 +1,141 @@
+use std::fmt;
+
+use heim_common::prelude::*;
+use heim_common::units::{ratio, time, Ratio, Time};
+
+use crate::sys;
+
+/// Percentage of time spent by CPU in each mode between two measurements.
+///
+/// Returned by the [CpuTimesTracker] methods.
+///
+/// ## Compatibility
+///
+/// For Linux additional modes can be retrieved with [CpuTimePercentExt] extension trait.
+///
+/// [CpuTimesTracker]: ./struct.CpuTimesTracker.html
+/// [CpuTimePercentExt]: ./os/linux/trait.CpuTimePercentExt.html
+pub struct CpuTimePercent {
+    delta: sys::CpuTime,
+}
+
+impl CpuTimePercent {
+    fn new(current: &sys::CpuTime, previous: &sys::CpuTime) -> CpuTimePercent {
+        CpuTimePercent {
+            delta: current.delta(previous),
+        }
+    }
+
+    /// Returns share of the mode time selected by `f` in the total time elapsed.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn ratio<F: FnOnce(&sys::CpuTime) -> Time>(&self, f: F) -> Ratio {
+        let total = self.delta.total().get::<time::second>();
+        if total > 0.0 {
+            let value = f(&self.delta).get::<time::second>();
+            Ratio::new::<ratio::ratio>((value / total) as f32)
+        } else {
+            Ratio::new::<ratio::ratio>(0.0)
+        }
+    }
+
+    /// Returns share of time spent by normal processes executing in user mode.
+    ///
+    /// ## Compatibility
+    ///
+    ///  * on Linux this also includes guest time
+    pub fn user(&self) -> Ratio {
+        self.ratio(sys::CpuTime::user)
+    }
+
+    /// Returns share of time spent by processes executing in kernel mode.
+    pub fn system(&self) -> Ratio {
+        self.ratio(sys::CpuTime::system)
+    }
+
+    /// Returns share of time spent doing nothing.
+    pub fn idle(&self) -> Ratio {
+        self.ratio(sys::CpuTime::idle)
+    }
+}
+
+impl fmt::Debug for CpuTimePercent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CpuTimePercent")
+            .field("user", &self.user())
+            .field("system", &self.system())
+            .field("idle", &self.idle())
+            .finish()
+    }
+}
+
+/// Tracker for the system-wide CPU usage.
+///
+/// It remembers the CPU times from the previous call,
+/// so each consequent call returns the [CPU time percentages]
+/// for the period elapsed since the previous call.
+/// First call returns percentages for the period since the system boot.
+///
+/// ## Example
+///
+/// ```rust
+/// # use heim_common::prelude::*;
+/// # use heim_common::units::ratio;
+/// # use heim_cpu::CpuTimesTracker;
+/// #
+/// # #[heim_derive::main]
+/// # async fn main() -> Result<()> {
+/// let mut tracker = CpuTimesTracker::new();
+/// let _ = tracker.time_percent().await?;
+/// // Some time later
+/// let usage = tracker.time_percent().await?;
+///
+/// println!("CPU is idle for {} %", usage.idle().get::<ratio::percent>());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [CPU time percentages]: ./struct.CpuTimePercent.html
+#[derive(Debug, Default)]
+pub struct CpuTimesTracker {
+    time: sys::CpuTime,
+    times: Vec<sys::CpuTime>,
+}
+
+impl CpuTimesTracker {
+    /// Creates new tracker.
+    pub fn new() -> CpuTimesTracker {
+        CpuTimesTracker::default()
+    }
+
+    /// Returns [CPU time percentages] for all CPUs since the previous call.
+    ///
+    /// [CPU time percentages]: ./struct.CpuTimePercent.html
+    pub async fn time_percent(&mut self) -> Result<CpuTimePercent> {
+        let current = sys::time().await?;
+        let percent = CpuTimePercent::new(&current, &self.time);
+        self.time = current;
+
+        Ok(percent)
+    }
+
+    /// Returns [CPU time percentages] for each CPU core since the previous call.
+    ///
+    /// Order of the returned values is the same to the [times] function.
+    /// CPU cores which were not present during the previous call
+    /// are measured since the system boot.
+    ///
+    /// [CPU time percentages]: ./struct.CpuTimePercent.html
+    /// [times]: ./fn.times.html
+    pub async fn times_percent(&mut self) -> Result<Vec<CpuTimePercent>> {
+        let current = sys::times().try_collect::<Vec<_>>().await?;
+        let empty = sys::CpuTime::default();
+        let percents = current
+            .iter()
+            .enumerate()
+            .map(|(idx, time)| CpuTimePercent::new(time, self.times.get(idx).unwrap_or(&empty)))
+            .collect();
+        self.times = current;
+
+        Ok(percents)
+    }
+}
@@ -109,6 +109,37 @@ async fn smoke_times() {
     }
 }
 
+#[heim_derive::test]
+async fn smoke_times_percent() {
+    let mut tracker = cpu::CpuTimesTracker::new();
+
+    let percent = tracker.time_percent().await.unwrap();
+    let _ = percent.user();
+    let _ = percent.system();
+    let _ = percent.idle();
+
+    #[cfg(target_os = "linux")]
+    {
+        use heim_cpu::os::linux::CpuTimePercentExt;
+
+        let _ = percent.nice();
+        let _ = percent.io_wait();
+        let _ = percent.irq();
+        let _ = percent.soft_irq();
+        let _ = percent.steal();
+    }
+
+    let _ = tracker.time_percent().await.unwrap();
+
+    let percents = tracker.times_percent().await.unwrap();
+    assert!(!percents.is_empty());
+    for percent in tracker.times_percent().await.unwrap() {
+        let _ = percent.user();
+        let _ = percent.system();
+        let _ = percent.idle();
+    }
+}
+
 #[heim_derive::test]
 async fn smoke_cpu_logical_count() {
     let count = cpu::logical_count().await.unwrap();
@@ -116,6 +147,15 @@ async fn smoke_cpu_logical_count() {
     assert!(count > 0);
 }
 
+#[heim_derive::test]
+async fn smoke_cpu_count_effective() {
+    let count = cpu::cpu_count_effective().await.unwrap();
+    let logical = cpu::logical_count().await.unwrap();
+
+    assert!(count > 0);
+    assert!(count <= logical);
+}
+
 // TODO: Crashes for some reasons in Azure VM, should be investigated
 #[heim_derive::skip_ci(target_os = "windows")]
 #[heim_derive::test]
@@ -1,5 +1,6 @@
 use std::ffi::{CString, OsStr};
 use std::io;
+use std::os::unix::ffi::OsStringExt;
 use std::str::FromStr;
 
 use heim_common::prelude::*;
@@ -61,7 +62,8 @@ impl IoCounters {
     // Based on the sysstat code:
     // https://github.com/sysstat/sysstat/blob/1c711c1fd03ac638cfc1b25cdf700625c173fd2c/common.c#L200
     async fn is_storage_device(&self) -> Result<bool> {
-        let path = CString::new(format!("/sys/block/{}", self.name.replace("/", "!")))?;
+        let path = rt::fs::sys_path("block").join(self.name.replace("/", "!"));
+        let path = CString::new(path.into_os_string().into_vec())?;
 
         let result =
             rt::task::spawn_blocking(move || unsafe { libc::access(path.as_ptr(), libc::F_OK) })
@@ -114,7 +116,7 @@ impl FromStr for IoCounters {
 }
 
 pub fn io_counters() -> impl Stream<Item = Result<IoCounters>> {
-    rt::fs::read_lines_into("/proc/diskstats")
+    rt::fs::read_lines_into(rt::fs::proc_path("diskstats"))
         .map_err(Into::into)
         .try_flatten_stream()
         .into_stream()
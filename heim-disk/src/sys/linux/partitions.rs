@@ -74,7 +74,7 @@ impl FromStr for Partition {
 
 // Returns stream with known physical (only!) partitions
 fn known_filesystems() -> impl Stream<Item = Result<FileSystem>> {
-    rt::fs::read_lines("/proc/filesystems")
+    rt::fs::read_lines(rt::fs::proc_path("filesystems"))
         .try_flatten_stream()
         .map_err(Error::from)
         .try_filter_map(|line| {
@@ -95,7 +95,7 @@ fn known_filesystems() -> impl Stream<Item = Result<FileSystem>> {
 }
 
 pub fn partitions() -> impl Stream<Item = Result<Partition>> {
-    rt::fs::read_lines(PROC_MOUNTS)
+    rt::fs::read_lines(rt::fs::proc_path("mounts"))
         .try_flatten_stream()
         .map_err(Error::from)
         .try_filter_map(|line| {
@@ -7,7 +7,7 @@ use heim_runtime as rt;
 const PROC_STAT: &str = "/proc/stat";
 
 pub async fn boot_time() -> Result<Time> {
-    let contents = rt::fs::read_to_string(PROC_STAT).await?;
+    let contents = rt::fs::read_to_string(rt::fs::proc_path("stat")).await?;
 
     for line in contents.lines() {
         if line.starts_with("btime ") {
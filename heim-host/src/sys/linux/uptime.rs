@@ -4,10 +4,8 @@ use heim_common::{
 };
 use heim_runtime as rt;
 
-const PROC_UPTIME: &str = "/proc/uptime";
-
 pub async fn uptime() -> Result<Time> {
-    let contents = rt::fs::read_to_string(PROC_UPTIME).await?;
+    let contents = rt::fs::read_to_string(rt::fs::proc_path("uptime")).await?;
 
     match contents.splitn(2, ' ').next() {
         Some(raw_value) => {
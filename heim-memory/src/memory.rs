@@ -50,3 +50,23 @@ impl fmt::Debug for Memory {
 pub async fn memory() -> Result<Memory> {
     sys::memory().await.map(Into::into)
 }
+
+/// Returns [Memory] information, clamped by the memory limit of the current process control group.
+///
+/// Inside the containers [memory] function returns the host memory information,
+/// while the workload is able to use only the memory allowed by the control group limit.
+/// This function returns the total memory no bigger than this limit,
+/// and available and free memory no bigger than the remaining amount until the limit is reached.
+///
+/// Values returned by the platform-specific extensions are not clamped.
+///
+/// ## Compatibility
+///
+/// Control groups are respected for Linux only,
+/// for other platforms this function returns the same value as [memory].
+///
+/// [Memory]: ./struct.Memory.html
+/// [memory]: ./fn.memory.html
+pub async fn memory_effective() -> Result<Memory> {
+    sys::memory_effective().await.map(Into::into)
+}
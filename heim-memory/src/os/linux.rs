@@ -31,6 +31,62 @@ pub trait MemoryExt {
     ///
     /// This is memory that has not been recently used and can be reclaimed for other purposes.
     fn inactive(&self) -> Information;
+
+    /// The amount of memory used by the in-kernel data structures cache.
+    fn slab(&self) -> Information;
+
+    /// Part of the [slab](#tymethod.slab) memory, that might be reclaimed, such as caches.
+    fn sreclaimable(&self) -> Information;
+
+    /// The amount of memory waiting to get written back to the disk.
+    fn dirty(&self) -> Information;
+
+    /// The amount of memory actively being written back to the disk.
+    fn writeback(&self) -> Information;
+
+    /// The amount of memory used for files which have been mapped, such as libraries.
+    fn mapped(&self) -> Information;
+
+    /// The amount of memory dedicated to the lowest level of page tables.
+    fn page_tables(&self) -> Information;
+
+    /// The total amount of memory currently available to be allocated on the system,
+    /// based on the overcommit ratio.
+    ///
+    /// This limit is only adhered to if strict overcommit accounting is enabled.
+    fn commit_limit(&self) -> Information;
+
+    /// The amount of memory presently allocated on the system.
+    ///
+    /// It might exceed the [commit limit](#tymethod.commit_limit)
+    /// if the overcommit is allowed.
+    fn committed_as(&self) -> Information;
+
+    /// The total amount of huge pages in the pool.
+    fn hugepages_total(&self) -> u64;
+
+    /// The amount of huge pages in the pool that are not yet allocated.
+    fn hugepages_free(&self) -> u64;
+
+    /// The amount of huge pages for which a commitment to allocate from the pool has been made,
+    /// but no allocation has yet been made.
+    fn hugepages_reserved(&self) -> u64;
+
+    /// The amount of huge pages in the pool above the configured base amount.
+    fn hugepages_surplus(&self) -> u64;
+
+    /// The size of the huge page.
+    fn hugepage_size(&self) -> Information;
+
+    /// Returns the raw value for any `/proc/meminfo` key, ex. `"SwapCached"`.
+    ///
+    /// Values are returned as is, which means that most of them are expressed in kilobytes,
+    /// while `HugePages_*` keys are representing pages amount.
+    ///
+    /// `None` is returned if the key is missing, which is possible for the keys
+    /// introduced in the newer kernels or hidden by the kernel configuration.
+    /// In the same case all the typed methods above are returning zero values.
+    fn raw(&self, key: &str) -> Option<u64>;
 }
 
 #[cfg(target_os = "linux")]
@@ -68,4 +124,60 @@ impl MemoryExt for Memory {
     fn inactive(&self) -> Information {
         self.as_ref().inactive()
     }
+
+    fn slab(&self) -> Information {
+        self.as_ref().slab()
+    }
+
+    fn sreclaimable(&self) -> Information {
+        self.as_ref().sreclaimable()
+    }
+
+    fn dirty(&self) -> Information {
+        self.as_ref().dirty()
+    }
+
+    fn writeback(&self) -> Information {
+        self.as_ref().writeback()
+    }
+
+    fn mapped(&self) -> Information {
+        self.as_ref().mapped()
+    }
+
+    fn page_tables(&self) -> Information {
+        self.as_ref().page_tables()
+    }
+
+    fn commit_limit(&self) -> Information {
+        self.as_ref().commit_limit()
+    }
+
+    fn committed_as(&self) -> Information {
+        self.as_ref().committed_as()
+    }
+
+    fn hugepages_total(&self) -> u64 {
+        self.as_ref().hugepages_total()
+    }
+
+    fn hugepages_free(&self) -> u64 {
+        self.as_ref().hugepages_free()
+    }
+
+    fn hugepages_reserved(&self) -> u64 {
+        self.as_ref().hugepages_reserved()
+    }
+
+    fn hugepages_surplus(&self) -> u64 {
+        self.as_ref().hugepages_surplus()
+    }
+
+    fn hugepage_size(&self) -> Information {
+        self.as_ref().hugepage_size()
+    }
+
+    fn raw(&self, key: &str) -> Option<u64> {
+        self.as_ref().raw(key)
+    }
 }
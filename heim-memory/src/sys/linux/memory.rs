@@ -1,23 +1,68 @@
-use std::io;
-use std::str::FromStr;
-
 use heim_common::prelude::{Error, Result};
 use heim_common::units::{information, Information};
+use heim_common::utils::iter::{ParseIterator, TryIterator};
 use heim_runtime as rt;
 
-#[derive(Debug, Default, Eq, PartialEq, Copy, Clone)]
+/// Keys which are required to be present in the `/proc/meminfo`.
+static REQUIRED_KEYS: &[&str] = &[
+    "MemTotal",
+    "MemFree",
+    "MemAvailable",
+    "Buffers",
+    "Cached",
+    "Active",
+    "Inactive",
+    "Shmem",
+];
+
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
 pub struct Memory {
-    total: Information,     // MemTotal
-    free: Information,      // MemFree
-    available: Information, // MemAvailable
-    buffers: Information,   // Buffers
-    cached: Information,    // Cached
-    active: Information,    // Active
-    inactive: Information,  // Inactive
-    shared: Information,    // Shmem
+    total: Information,         // MemTotal
+    free: Information,          // MemFree
+    available: Information,     // MemAvailable
+    buffers: Information,       // Buffers
+    cached: Information,        // Cached
+    active: Information,        // Active
+    inactive: Information,      // Inactive
+    shared: Information,        // Shmem
+    slab: Information,          // Slab
+    sreclaimable: Information,  // SReclaimable
+    dirty: Information,         // Dirty
+    writeback: Information,     // Writeback
+    mapped: Information,        // Mapped
+    page_tables: Information,   // PageTables
+    commit_limit: Information,  // CommitLimit
+    committed_as: Information,  // Committed_AS
+    hugepages_total: u64,       // HugePages_Total
+    hugepages_free: u64,        // HugePages_Free
+    hugepages_reserved: u64,    // HugePages_Rsvd
+    hugepages_surplus: u64,     // HugePages_Surp
+    hugepage_size: Information, // Hugepagesize
+
+    // `/proc/meminfo` contents, other keys are parsed on demand by the `raw` method
+    contents: String,
+}
+
+/// Splits `/proc/meminfo` line into the key and its value.
+///
+/// Value is followed by an optional `kB` unit, which is skipped.
+fn split_line(line: &str) -> Option<(&str, &str)> {
+    let mut parts = line.splitn(2, ':');
+    let key = parts.next()?;
+    let value = parts.next()?.split_ascii_whitespace().next()?;
+
+    Some((key, value))
 }
 
 impl Memory {
+    pub fn raw(&self, key: &str) -> Option<u64> {
+        self.contents
+            .lines()
+            .filter_map(split_line)
+            .find(|(name, _)| *name == key)
+            .and_then(|(_, value)| value.parse().ok())
+    }
+
     pub fn total(&self) -> Information {
         self.total
     }
@@ -42,69 +87,240 @@ impl Memory {
     pub fn shared(&self) -> Information {
         self.shared
     }
-}
+    pub fn slab(&self) -> Information {
+        self.slab
+    }
+    pub fn sreclaimable(&self) -> Information {
+        self.sreclaimable
+    }
+    pub fn dirty(&self) -> Information {
+        self.dirty
+    }
+    pub fn writeback(&self) -> Information {
+        self.writeback
+    }
+    pub fn mapped(&self) -> Information {
+        self.mapped
+    }
+    pub fn page_tables(&self) -> Information {
+        self.page_tables
+    }
+    pub fn commit_limit(&self) -> Information {
+        self.commit_limit
+    }
+    pub fn committed_as(&self) -> Information {
+        self.committed_as
+    }
+    pub fn hugepages_total(&self) -> u64 {
+        self.hugepages_total
+    }
+    pub fn hugepages_free(&self) -> u64 {
+        self.hugepages_free
+    }
+    pub fn hugepages_reserved(&self) -> u64 {
+        self.hugepages_reserved
+    }
+    pub fn hugepages_surplus(&self) -> u64 {
+        self.hugepages_surplus
+    }
+    pub fn hugepage_size(&self) -> Information {
+        self.hugepage_size
+    }
+
+    /// Clamps total, available and free memory by the control group `limit`,
+    /// considering memory `usage` by the control group processes.
+    ///
+    /// Raw values are left untouched.
+    fn clamp(mut self, limit: Information, usage: Information) -> Memory {
+        let remaining = Information::new::<information::kilobyte>(
+            limit
+                .get::<information::kilobyte>()
+                .saturating_sub(usage.get::<information::kilobyte>()),
+        );
 
-impl FromStr for Memory {
-    type Err = Error;
+        if self.total > limit {
+            self.total = limit;
+        }
+        if self.available > remaining {
+            self.available = remaining;
+        }
+        if self.free > remaining {
+            self.free = remaining;
+        }
+
+        self
+    }
 
-    fn from_str(meminfo: &str) -> Result<Self> {
+    fn parse(contents: String) -> Result<Memory> {
         let mut memory = Memory::default();
-        let mut matched_lines = 0u8;
-
-        for line in meminfo.lines() {
-            // If line does not starts with "Me", "Ac" or other options at all,
-            // we do not need that key at all
-            let first_bytes = &line.as_bytes()[..2];
-            match first_bytes {
-                b"Me" | b"Ac" | b"In" | b"Bu" | b"Ca" | b"Sh" => {}
-                _ => continue,
-            };
+        let mut required = 0;
 
+        for line in contents.lines() {
             let mut parts = line.splitn(2, ':');
-            let field = match parts.next() {
-                Some("MemTotal") => &mut memory.total,
-                Some("MemFree") => &mut memory.free,
-                Some("MemAvailable") => &mut memory.available,
-                Some("Buffers") => &mut memory.buffers,
-                Some("Cached") => &mut memory.cached,
-                Some("Active") => &mut memory.active,
-                Some("Inactive") => &mut memory.inactive,
-                Some("Shmem") => &mut memory.shared,
-                _ => continue,
+            let key = parts.try_next()?;
+            let mut value = match parts.next() {
+                Some(value) => value.split_ascii_whitespace(),
+                None => continue,
             };
 
-            match parts.next() {
-                Some(value) => {
-                    *field = {
-                        let bytes = match value.trim_start().splitn(2, ' ').next() {
-                            Some(kbytes) => {
-                                let value = kbytes.parse::<u64>()?;
-                                Information::new::<information::kilobyte>(value)
-                            }
-                            None => continue,
-                        };
-
-                        matched_lines += 1;
-
-                        bytes
-                    }
+            // Only the known keys are parsed here, see `Memory::raw` for the rest of them
+            let field = match key {
+                "MemTotal" => &mut memory.total,
+                "MemFree" => &mut memory.free,
+                "MemAvailable" => &mut memory.available,
+                "Buffers" => &mut memory.buffers,
+                "Cached" => &mut memory.cached,
+                "Active" => &mut memory.active,
+                "Inactive" => &mut memory.inactive,
+                "Shmem" => &mut memory.shared,
+                "Slab" => &mut memory.slab,
+                "SReclaimable" => &mut memory.sreclaimable,
+                "Dirty" => &mut memory.dirty,
+                "Writeback" => &mut memory.writeback,
+                "Mapped" => &mut memory.mapped,
+                "PageTables" => &mut memory.page_tables,
+                "CommitLimit" => &mut memory.commit_limit,
+                "Committed_AS" => &mut memory.committed_as,
+                "Hugepagesize" => &mut memory.hugepage_size,
+                _ => {
+                    let pages = match key {
+                        "HugePages_Total" => &mut memory.hugepages_total,
+                        "HugePages_Free" => &mut memory.hugepages_free,
+                        "HugePages_Rsvd" => &mut memory.hugepages_reserved,
+                        "HugePages_Surp" => &mut memory.hugepages_surplus,
+                        _ => continue,
+                    };
+                    *pages = value.try_parse_next()?;
+                    continue;
                 }
-                None => continue,
+            };
+
+            *field = Information::new::<information::kilobyte>(value.try_parse_next()?);
+            if REQUIRED_KEYS.contains(&key) {
+                required += 1;
             }
+        }
+
+        memory.contents = contents;
 
-            if matched_lines == 8 {
-                return Ok(memory);
+        if required < REQUIRED_KEYS.len() {
+            for key in REQUIRED_KEYS {
+                if memory.raw(key).is_none() {
+                    return Err(Error::missing_key(*key, "/proc/meminfo"));
+                }
             }
         }
 
-        // `FromStr` knows nothing about `/proc/meminfo`,
-        // but at this point we are not tracking which exact field are we missing.
-        // TODO: Rewrite parser and use `Error::missing_key` instead
-        let inner = io::Error::from(io::ErrorKind::InvalidData);
-        Err(Error::from(inner).with_file("/proc/meminfo"))
+        Ok(memory)
     }
 }
 
 pub async fn memory() -> Result<Memory> {
-    rt::fs::read_into("/proc/meminfo").await
+    let path = rt::fs::proc_path("meminfo");
+    let contents = match rt::fs::read_to_string(&path).await {
+        Ok(contents) => contents,
+        Err(e) => return Err(Error::from(e).with_file(path)),
+    };
+
+    Memory::parse(contents)
+}
+
+pub async fn memory_effective() -> Result<Memory> {
+    let memory = memory().await?;
+
+    // Control group might be not available at all (ex. cgroup filesystem is not mounted),
+    // in that case process is not limited by it.
+    let cgroup = match heim_cgroup::cgroup().await {
+        Ok(cgroup) => cgroup,
+        Err(..) => return Ok(memory),
+    };
+    match cgroup.memory().await {
+        Ok(ref accounting) => match accounting.limit() {
+            Some(limit) => {
+                let usage = accounting
+                    .usage()
+                    .unwrap_or_else(|| Information::new::<information::byte>(0));
+
+                Ok(memory.clamp(limit, usage))
+            }
+            None => Ok(memory),
+        },
+        Err(..) => Ok(memory),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::{information, Information};
+
+    use super::Memory;
+
+    static MEMINFO: &str = "MemTotal:       16318464 kB
+MemFree:         1269800 kB
+MemAvailable:    9358148 kB
+Buffers:          641048 kB
+Cached:          7623220 kB
+SwapCached:         4616 kB
+Active:          8614092 kB
+Inactive:        5226772 kB
+Dirty:              1144 kB
+Writeback:            16 kB
+Mapped:           975416 kB
+Shmem:            566744 kB
+Slab:             783444 kB
+SReclaimable:     617432 kB
+PageTables:        58224 kB
+CommitLimit:    12353376 kB
+Committed_AS:   16054604 kB
+HugePages_Total:       4
+HugePages_Free:        2
+HugePages_Rsvd:        1
+HugePages_Surp:        0
+Hugepagesize:       2048 kB
+";
+
+    #[test]
+    fn test_parse() {
+        let memory = Memory::parse(MEMINFO.to_string()).unwrap();
+
+        assert_eq!(memory.total().get::<information::kilobyte>(), 16_318_464);
+        assert_eq!(memory.shared().get::<information::kilobyte>(), 566_744);
+        assert_eq!(memory.dirty().get::<information::kilobyte>(), 1144);
+        assert_eq!(memory.writeback().get::<information::kilobyte>(), 16);
+        assert_eq!(
+            memory.sreclaimable().get::<information::kilobyte>(),
+            617_432
+        );
+        assert_eq!(
+            memory.committed_as().get::<information::kilobyte>(),
+            16_054_604
+        );
+        assert_eq!(memory.hugepages_total(), 4);
+        assert_eq!(memory.hugepages_reserved(), 1);
+        assert_eq!(memory.hugepage_size().get::<information::kilobyte>(), 2048);
+        assert_eq!(memory.raw("SwapCached"), Some(4616));
+        assert_eq!(memory.raw("DirectMap1G"), None);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let memory = Memory::parse(MEMINFO.to_string()).unwrap().clamp(
+            Information::new::<information::kilobyte>(2_000_000),
+            Information::new::<information::kilobyte>(1_500_000),
+        );
+
+        assert_eq!(memory.total().get::<information::kilobyte>(), 2_000_000);
+        assert_eq!(memory.available().get::<information::kilobyte>(), 500_000);
+        assert_eq!(memory.free().get::<information::kilobyte>(), 500_000);
+        // Other values are left untouched
+        assert_eq!(memory.cached().get::<information::kilobyte>(), 7_623_220);
+        assert_eq!(memory.raw("MemTotal"), Some(16_318_464));
+    }
+
+    #[test]
+    fn test_missing_key() {
+        let meminfo = MEMINFO.replace("MemAvailable", "MemUnavailable");
+
+        assert!(Memory::parse(meminfo).is_err());
+    }
 }
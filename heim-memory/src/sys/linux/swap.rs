@@ -5,7 +5,6 @@ use heim_common::prelude::*;
 use heim_common::units::{information, Information};
 use heim_runtime as rt;
 
-static PROC_VMSTAT: &str = "/proc/vmstat";
 static PROC_MEMINFO: &str = "/proc/meminfo";
 
 #[derive(Debug, Default, Clone)]
@@ -140,12 +139,12 @@ impl Swap {
 }
 
 async fn vm_stat() -> Result<VmStat> {
-    rt::fs::read_into(PROC_VMSTAT).await
+    rt::fs::read_into(rt::fs::proc_path("vmstat")).await
 }
 
 pub async fn swap() -> Result<Swap> {
     let (meminfo, vm_stat) = future::try_join(
-        rt::fs::read_to_string(PROC_MEMINFO).map_err(Into::into),
+        rt::fs::read_to_string(rt::fs::proc_path("meminfo")).map_err(Into::into),
         vm_stat(),
     )
     .await?;
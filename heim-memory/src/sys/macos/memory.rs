@@ -75,3 +75,7 @@ pub async fn memory() -> Result<Memory> {
         wire,
     })
 }
+
+pub async fn memory_effective() -> Result<Memory> {
+    memory().await
+}
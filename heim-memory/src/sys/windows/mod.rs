@@ -84,3 +84,7 @@ pub async fn swap() -> Result<Swap> {
 pub async fn memory() -> Result<Memory> {
     memory_status().await.map(Memory)
 }
+
+pub async fn memory_effective() -> Result<Memory> {
+    memory().await
+}
@@ -22,6 +22,20 @@ async fn smoke_memory() {
         let _ = mem.shared();
         let _ = mem.active();
         let _ = mem.inactive();
+        let _ = mem.slab();
+        let _ = mem.sreclaimable();
+        let _ = mem.dirty();
+        let _ = mem.writeback();
+        let _ = mem.mapped();
+        let _ = mem.page_tables();
+        let _ = mem.commit_limit();
+        let _ = mem.committed_as();
+        let _ = mem.hugepages_total();
+        let _ = mem.hugepages_free();
+        let _ = mem.hugepages_reserved();
+        let _ = mem.hugepages_surplus();
+        let _ = mem.hugepage_size();
+        assert!(mem.raw("MemTotal").is_some());
     }
 
     #[cfg(target_os = "macos")]
@@ -34,6 +48,17 @@ async fn smoke_memory() {
     }
 }
 
+#[heim_derive::test]
+async fn smoke_memory_effective() {
+    let mem = memory::memory_effective().await.unwrap();
+    let host = memory::memory().await.unwrap();
+
+    assert!(mem.total().get::<information::byte>() > 0);
+    assert!(mem.total() <= host.total());
+    let _ = mem.available();
+    let _ = mem.free();
+}
+
 #[heim_derive::test]
 #[allow(unused_variables)]
 async fn smoke_swap() {
@@ -0,0 +1,175 @@
+use std::fmt;
+use std::net::SocketAddr;
+use std::path::Path;
+
+use heim_common::prelude::*;
+
+use crate::sys;
+
+/// Socket protocol of the [Connection].
+///
+/// [Connection]: ./struct.Connection.html
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Protocol {
+    /// TCP over IPv4
+    Tcp,
+    /// TCP over IPv6
+    Tcp6,
+    /// UDP over IPv4
+    Udp,
+    /// UDP over IPv6
+    Udp6,
+    /// UNIX domain socket
+    Unix,
+}
+
+/// Kind of the connections to fetch, used as a filter for the [connections] function.
+///
+/// [connections]: ./fn.connections.html
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ConnectionKind {
+    /// All the connections, including UNIX sockets
+    All,
+    /// TCP and UDP connections over both IPv4 and IPv6
+    Inet,
+    /// TCP and UDP connections over IPv4
+    Inet4,
+    /// TCP and UDP connections over IPv6
+    Inet6,
+    /// TCP connections over both IPv4 and IPv6
+    Tcp,
+    /// TCP connections over IPv4
+    Tcp4,
+    /// TCP connections over IPv6
+    Tcp6,
+    /// UDP connections over both IPv4 and IPv6
+    Udp,
+    /// UDP connections over IPv4
+    Udp4,
+    /// UDP connections over IPv6
+    Udp6,
+    /// UNIX domain sockets
+    Unix,
+}
+
+impl ConnectionKind {
+    /// Returns socket protocols matching this connection kind.
+    pub fn protocols(self) -> &'static [Protocol] {
+        match self {
+            ConnectionKind::All => &[
+                Protocol::Tcp,
+                Protocol::Tcp6,
+                Protocol::Udp,
+                Protocol::Udp6,
+                Protocol::Unix,
+            ],
+            ConnectionKind::Inet => &[Protocol::Tcp, Protocol::Tcp6, Protocol::Udp, Protocol::Udp6],
+            ConnectionKind::Inet4 => &[Protocol::Tcp, Protocol::Udp],
+            ConnectionKind::Inet6 => &[Protocol::Tcp6, Protocol::Udp6],
+            ConnectionKind::Tcp => &[Protocol::Tcp, Protocol::Tcp6],
+            ConnectionKind::Tcp4 => &[Protocol::Tcp],
+            ConnectionKind::Tcp6 => &[Protocol::Tcp6],
+            ConnectionKind::Udp => &[Protocol::Udp, Protocol::Udp6],
+            ConnectionKind::Udp4 => &[Protocol::Udp],
+            ConnectionKind::Udp6 => &[Protocol::Udp6],
+            ConnectionKind::Unix => &[Protocol::Unix],
+        }
+    }
+}
+
+/// Connection state.
+///
+/// TCP sockets are reporting their TCP state,
+/// for UDP and UNIX sockets it is always the `ConnectionState::None`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ConnectionState {
+    /// Connection is established
+    Established,
+    /// Connection request was sent
+    SynSent,
+    /// Connection request was received
+    SynRecv,
+    /// Socket is closed, connection is shutting down
+    FinWait1,
+    /// Connection is closed, waiting for the remote end to shut down
+    FinWait2,
+    /// Socket is waiting after close to handle packets still in the network
+    TimeWait,
+    /// Socket is not being used
+    Close,
+    /// Remote end has shut down, waiting for the socket to close
+    CloseWait,
+    /// Remote end has shut down and socket is closed, waiting for acknowledgement
+    LastAck,
+    /// Socket is listening for incoming connections
+    Listen,
+    /// Both sockets are shut down but not all data was sent
+    Closing,
+    /// Connection state is not applicable for this protocol
+    None,
+}
+
+/// Network connection.
+pub struct Connection(sys::Connection);
+
+wrap!(Connection, sys::Connection);
+
+impl Connection {
+    /// Returns socket protocol.
+    pub fn protocol(&self) -> Protocol {
+        self.as_ref().protocol()
+    }
+
+    /// Returns local address of the TCP or UDP socket.
+    ///
+    /// For UNIX sockets `None` is returned, see [`Connection::path`] instead.
+    ///
+    /// [`Connection::path`]: #method.path
+    pub fn local_address(&self) -> Option<SocketAddr> {
+        self.as_ref().local_address()
+    }
+
+    /// Returns remote address of the TCP or UDP socket.
+    ///
+    /// `None` is returned if the socket is not connected
+    /// (ex. it is a listening TCP socket) or if it is a UNIX socket.
+    pub fn remote_address(&self) -> Option<SocketAddr> {
+        self.as_ref().remote_address()
+    }
+
+    /// Returns path of the bound UNIX socket.
+    ///
+    /// Abstract socket names are prefixed with the `@` symbol.
+    /// For unbound UNIX sockets and for TCP and UDP sockets `None` is returned.
+    pub fn path(&self) -> Option<&Path> {
+        self.as_ref().path()
+    }
+
+    /// Returns connection state.
+    pub fn state(&self) -> ConnectionState {
+        self.as_ref().state()
+    }
+}
+
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("protocol", &self.protocol())
+            .field("local_address", &self.local_address())
+            .field("remote_address", &self.remote_address())
+            .field("path", &self.path())
+            .field("state", &self.state())
+            .finish()
+    }
+}
+
+/// Returns a stream over the system-wide network [connections] of the `kind` given.
+///
+/// ## Compatibility
+///
+/// Implemented only for Linux for now. For other platforms will return an empty stream.
+///
+/// [connections]: ./struct.Connection.html
+pub fn connections(kind: ConnectionKind) -> impl Stream<Item = Result<Connection>> {
+    sys::connections(kind).map_ok(Into::into)
+}
@@ -0,0 +1,118 @@
+use std::net;
+
+use heim_common::prelude::*;
+
+use crate::{sys, Address};
+
+bitflags::bitflags! {
+    /// Network interface flags.
+    ///
+    /// See [Interface::flags] method, which returns that structure.
+    ///
+    /// [Interface::flags]: ./struct.Interface.html#method.flags
+    pub struct InterfaceFlags: u32 {
+        /// Interface is administratively up.
+        const UP = 1;
+        /// Interface has a valid broadcast address.
+        const BROADCAST = 1 << 1;
+        /// Interface is a loopback.
+        const LOOPBACK = 1 << 2;
+        /// Interface is a point-to-point link.
+        const POINT_TO_POINT = 1 << 3;
+        /// Interface resources are allocated.
+        const RUNNING = 1 << 4;
+        /// Interface supports multicast.
+        const MULTICAST = 1 << 5;
+    }
+}
+
+/// Address assigned to the network [Interface].
+///
+/// [Interface]: ./struct.Interface.html
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct InterfaceAddress {
+    pub(crate) address: Address,
+    pub(crate) netmask: Option<Address>,
+    pub(crate) broadcast: Option<Address>,
+    pub(crate) destination: Option<Address>,
+}
+
+impl InterfaceAddress {
+    /// Returns the address.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Returns netmask address if available.
+    pub fn netmask(&self) -> Option<Address> {
+        self.netmask
+    }
+
+    /// Returns broadcast address if available.
+    pub fn broadcast(&self) -> Option<Address> {
+        self.broadcast
+    }
+
+    /// Returns destination address for point-to-point interfaces if available.
+    pub fn destination(&self) -> Option<Address> {
+        self.destination
+    }
+
+    /// Returns network prefix length, calculated from the netmask.
+    ///
+    /// `None` is returned for link addresses or if netmask is not available.
+    pub fn prefix_len(&self) -> Option<u8> {
+        // Netmasks are expected to be contiguous, so counting bits set is enough
+        match self.netmask? {
+            Address::Inet(net::SocketAddr::V4(mask)) => {
+                Some(u32::from(*mask.ip()).count_ones() as u8)
+            }
+            Address::Inet6(net::SocketAddr::V6(mask)) => {
+                Some(u128::from(*mask.ip()).count_ones() as u8)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Network interface with all the addresses assigned to it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Interface {
+    pub(crate) name: String,
+    pub(crate) index: u32,
+    pub(crate) flags: InterfaceFlags,
+    pub(crate) addresses: Vec<InterfaceAddress>,
+}
+
+impl Interface {
+    /// Returns interface name.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns interface index.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns interface flags.
+    pub fn flags(&self) -> InterfaceFlags {
+        self.flags
+    }
+
+    /// Returns addresses assigned to the interface.
+    pub fn addresses(&self) -> &[InterfaceAddress] {
+        &self.addresses
+    }
+}
+
+/// Returns a stream over the network [interfaces].
+///
+/// Unlike the [nic] function, which yields an item per each interface address,
+/// this one yields one item per interface, grouping all of its addresses together.
+///
+/// [interfaces]: struct.Interface.html
+/// [nic]: fn.nic.html
+pub fn interfaces() -> impl Stream<Item = Result<Interface>> {
+    sys::interfaces()
+}
@@ -27,11 +27,17 @@
 pub mod os;
 mod sys;
 
+mod connections;
 mod counters;
+mod interface;
 mod nic;
+mod route;
 
+pub use self::connections::*;
 pub use self::counters::*;
+pub use self::interface::*;
 pub use self::nic::*;
+pub use self::route::*;
 
 // Re-exports
 pub use macaddr::{MacAddr, MacAddr6, MacAddr8};
@@ -66,6 +66,11 @@ impl Nic {
     pub fn is_multicast(&self) -> bool {
         self.as_ref().is_multicast()
     }
+
+    /// Returns interface MTU (maximum transmission unit) in bytes.
+    pub async fn mtu(&self) -> Result<u32> {
+        self.as_ref().mtu().await
+    }
 }
 
 impl fmt::Debug for Nic {
@@ -0,0 +1,17 @@
+/// Linux-specific extension for [Connection].
+///
+/// [Connection]: ../../struct.Connection.html
+pub trait ConnectionExt {
+    /// Returns inode number of the socket.
+    ///
+    /// Inode number can be used to match this connection
+    /// with the `socket:[inode]` file descriptors of the processes.
+    fn inode(&self) -> u64;
+}
+
+#[cfg(target_os = "linux")]
+impl ConnectionExt for crate::Connection {
+    fn inode(&self) -> u64 {
+        self.as_ref().inode()
+    }
+}
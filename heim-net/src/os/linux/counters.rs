@@ -7,12 +7,145 @@ use heim_common::Pid;
 
 use crate::{sys, IoCounters};
 
+/// Detailed network interface statistics.
+///
+/// Returned by [IoCountersExt::statistics] method, values are loaded
+/// from the `/sys/class/net/<interface>/statistics/` directory
+/// and are named after the corresponding files there.
+/// Counters not supported by the running kernel are `None`.
+///
+/// [IoCountersExt::statistics]: ./trait.IoCountersExt.html#tymethod.statistics
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Statistics {
+    pub(crate) rx_crc_errors: Option<u64>,
+    pub(crate) rx_frame_errors: Option<u64>,
+    pub(crate) rx_length_errors: Option<u64>,
+    pub(crate) rx_missed_errors: Option<u64>,
+    pub(crate) rx_over_errors: Option<u64>,
+    pub(crate) rx_fifo_errors: Option<u64>,
+    pub(crate) rx_nohandler: Option<u64>,
+    pub(crate) tx_aborted_errors: Option<u64>,
+    pub(crate) tx_carrier_errors: Option<u64>,
+    pub(crate) tx_fifo_errors: Option<u64>,
+    pub(crate) tx_heartbeat_errors: Option<u64>,
+    pub(crate) tx_window_errors: Option<u64>,
+    pub(crate) collisions: Option<u64>,
+}
+
+impl Statistics {
+    /// Returns amount of packets received with CRC errors.
+    pub fn rx_crc_errors(&self) -> Option<u64> {
+        self.rx_crc_errors
+    }
+
+    /// Returns amount of packets received with frame alignment errors.
+    pub fn rx_frame_errors(&self) -> Option<u64> {
+        self.rx_frame_errors
+    }
+
+    /// Returns amount of packets received with invalid length.
+    pub fn rx_length_errors(&self) -> Option<u64> {
+        self.rx_length_errors
+    }
+
+    /// Returns amount of packets missed by the host, ex. because of the NIC buffer overflow.
+    pub fn rx_missed_errors(&self) -> Option<u64> {
+        self.rx_missed_errors
+    }
+
+    /// Returns amount of receiver overruns.
+    pub fn rx_over_errors(&self) -> Option<u64> {
+        self.rx_over_errors
+    }
+
+    /// Returns amount of receiver FIFO errors.
+    pub fn rx_fifo_errors(&self) -> Option<u64> {
+        self.rx_fifo_errors
+    }
+
+    /// Returns amount of packets dropped because of no handler for the protocol.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux 4.6+, older versions always returns `None`.
+    pub fn rx_nohandler(&self) -> Option<u64> {
+        self.rx_nohandler
+    }
+
+    /// Returns amount of packets, which transmission was aborted.
+    pub fn tx_aborted_errors(&self) -> Option<u64> {
+        self.tx_aborted_errors
+    }
+
+    /// Returns amount of packets not sent because of the carrier errors.
+    pub fn tx_carrier_errors(&self) -> Option<u64> {
+        self.tx_carrier_errors
+    }
+
+    /// Returns amount of transmitter FIFO errors.
+    pub fn tx_fifo_errors(&self) -> Option<u64> {
+        self.tx_fifo_errors
+    }
+
+    /// Returns amount of heartbeat errors.
+    pub fn tx_heartbeat_errors(&self) -> Option<u64> {
+        self.tx_heartbeat_errors
+    }
+
+    /// Returns amount of late collision errors.
+    pub fn tx_window_errors(&self) -> Option<u64> {
+        self.tx_window_errors
+    }
+
+    /// Returns amount of collisions detected while sending packets.
+    pub fn collisions(&self) -> Option<u64> {
+        self.collisions
+    }
+}
+
 /// Linux-specific extension for [IoCounters].
 ///
 /// [IoCounters]: ../../struct.IoCounters.html
 pub trait IoCountersExt {
     /// Returns packets amount which were dropped while sending them.
     fn drop_sent(&self) -> u64;
+
+    /// Returns amount of receiver FIFO buffer errors.
+    fn fifo_recv(&self) -> u64;
+
+    /// Returns amount of packets received with frame alignment errors.
+    fn frame_recv(&self) -> u64;
+
+    /// Returns amount of compressed packets received.
+    fn compressed_recv(&self) -> u64;
+
+    /// Returns amount of multicast packets received.
+    fn multicast_recv(&self) -> u64;
+
+    /// Returns amount of transmitter FIFO buffer errors.
+    fn fifo_sent(&self) -> u64;
+
+    /// Returns amount of collisions detected while sending packets.
+    fn collisions(&self) -> u64;
+
+    /// Returns amount of carrier losses detected while sending packets.
+    fn carrier_sent(&self) -> u64;
+
+    /// Returns amount of compressed packets sent.
+    fn compressed_sent(&self) -> u64;
+
+    /// Returns detailed interface statistics.
+    ///
+    /// Statistics are available for the network namespace of the current process only,
+    /// so an error is returned for the counters loaded with the [io_counters_for_pid] function
+    /// if that process belongs to the other network namespace.
+    /// Error is also returned if the interface does not exist anymore.
+    ///
+    /// [io_counters_for_pid]: ./fn.io_counters_for_pid.html
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn statistics(&self) -> BoxFuture<Result<Statistics>>;
 }
 
 #[cfg(target_os = "linux")]
@@ -20,6 +153,42 @@ impl IoCountersExt for crate::IoCounters {
     fn drop_sent(&self) -> u64 {
         self.as_ref().drop_sent()
     }
+
+    fn fifo_recv(&self) -> u64 {
+        self.as_ref().fifo_recv()
+    }
+
+    fn frame_recv(&self) -> u64 {
+        self.as_ref().frame_recv()
+    }
+
+    fn compressed_recv(&self) -> u64 {
+        self.as_ref().compressed_recv()
+    }
+
+    fn multicast_recv(&self) -> u64 {
+        self.as_ref().multicast_recv()
+    }
+
+    fn fifo_sent(&self) -> u64 {
+        self.as_ref().fifo_sent()
+    }
+
+    fn collisions(&self) -> u64 {
+        self.as_ref().collisions()
+    }
+
+    fn carrier_sent(&self) -> u64 {
+        self.as_ref().carrier_sent()
+    }
+
+    fn compressed_sent(&self) -> u64 {
+        self.as_ref().compressed_sent()
+    }
+
+    fn statistics(&self) -> BoxFuture<Result<Statistics>> {
+        self.as_ref().statistics().boxed()
+    }
 }
 
 /// Returns stream which yield [IO counters] for each network interface for process with given `pid`.
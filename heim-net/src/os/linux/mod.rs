@@ -2,8 +2,12 @@
 //!
 //! Available only for `cfg(target_os = "linux")`
 
+mod connections;
 mod counters;
 mod nic;
+mod protocol;
 
+pub use self::connections::*;
 pub use self::counters::*;
 pub use self::nic::*;
+pub use self::protocol::*;
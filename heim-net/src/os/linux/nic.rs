@@ -1,7 +1,47 @@
+#[cfg(target_os = "linux")]
+use heim_common::prelude::FutureExt;
+use heim_common::prelude::{BoxFuture, Result};
+use heim_common::units::InformationRate;
+
 use crate::Address;
 
+/// Network interface duplex mode.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Duplex {
+    /// Full-duplex, data can be sent and received simultaneously.
+    Full,
+    /// Half-duplex, data can be either sent or received at a time.
+    Half,
+    /// Duplex mode is unknown, ex. for virtual interfaces or when link is down.
+    Unknown,
+}
+
+/// Network interface operational state.
+///
+/// See RFC 2863 for details.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum OperState {
+    /// Interface state is unknown.
+    Unknown,
+    /// Some interface component is missing.
+    NotPresent,
+    /// Interface is unable to transfer data.
+    Down,
+    /// Interface is down because of the lower-layer interface state.
+    LowerLayerDown,
+    /// Interface is in the test mode.
+    Testing,
+    /// Interface is waiting for an external event.
+    Dormant,
+    /// Interface is able to transfer data.
+    Up,
+}
+
 /// Linux-specific extension for [Nic].
 ///
+/// All the link properties are loaded from the `/sys/class/net/<interface>/` directory.
+/// For IPv4 address aliases, ex. `eth0:1`, properties of the underlying link are returned.
+///
 /// [Nic]: ../../struct.Nic.html
 pub trait NicExt {
     /// Returns broadcast address if available.
@@ -12,6 +52,48 @@ pub trait NicExt {
 
     /// Returns `bool` indicating whether interface is point-to-point.
     fn is_point_to_point(&self) -> bool;
+
+    /// Returns link speed.
+    ///
+    /// `None` is returned if speed is unknown, ex. for virtual interfaces or when link is down.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn speed(&self) -> BoxFuture<Result<Option<InformationRate>>>;
+
+    /// Returns link duplex mode.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn duplex(&self) -> BoxFuture<Result<Duplex>>;
+
+    /// Returns interface operational state.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn operstate(&self) -> BoxFuture<Result<OperState>>;
+
+    /// Returns `bool` indicating whether physical link is up.
+    ///
+    /// `None` is returned if interface is administratively down.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn carrier(&self) -> BoxFuture<Result<Option<bool>>>;
+
+    /// Returns length of the interface transmit queue.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn tx_queue_len(&self) -> BoxFuture<Result<u64>>;
+
+    /// Returns name of the kernel driver used by the interface device.
+    ///
+    /// `None` is returned for virtual interfaces, which have no device.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn driver(&self) -> BoxFuture<Result<Option<String>>>;
 }
 
 #[cfg(target_os = "linux")]
@@ -27,4 +109,28 @@ impl NicExt for crate::Nic {
     fn is_point_to_point(&self) -> bool {
         self.as_ref().is_point_to_point()
     }
+
+    fn speed(&self) -> BoxFuture<Result<Option<InformationRate>>> {
+        self.as_ref().speed().boxed()
+    }
+
+    fn duplex(&self) -> BoxFuture<Result<Duplex>> {
+        self.as_ref().duplex().boxed()
+    }
+
+    fn operstate(&self) -> BoxFuture<Result<OperState>> {
+        self.as_ref().operstate().boxed()
+    }
+
+    fn carrier(&self) -> BoxFuture<Result<Option<bool>>> {
+        self.as_ref().carrier().boxed()
+    }
+
+    fn tx_queue_len(&self) -> BoxFuture<Result<u64>> {
+        self.as_ref().tx_queue_len().boxed()
+    }
+
+    fn driver(&self) -> BoxFuture<Result<Option<String>>> {
+        self.as_ref().driver().boxed()
+    }
 }
@@ -0,0 +1,371 @@
+#[cfg(target_os = "linux")]
+use heim_common::prelude::Result;
+
+#[cfg(target_os = "linux")]
+use crate::sys;
+
+/// IP protocol statistics.
+///
+/// See RFC 4293 for details about the counters.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct IpStats {
+    pub(crate) in_receives: u64,
+    pub(crate) in_hdr_errors: u64,
+    pub(crate) in_addr_errors: u64,
+    pub(crate) in_discards: u64,
+    pub(crate) in_delivers: u64,
+    pub(crate) out_requests: u64,
+    pub(crate) out_discards: u64,
+    pub(crate) out_no_routes: u64,
+    pub(crate) reasm_reqds: u64,
+    pub(crate) reasm_oks: u64,
+    pub(crate) reasm_fails: u64,
+    pub(crate) frag_oks: u64,
+    pub(crate) frag_fails: u64,
+    pub(crate) frag_creates: u64,
+}
+
+impl IpStats {
+    /// Returns amount of datagrams received, including ones received in error.
+    pub fn in_receives(&self) -> u64 {
+        self.in_receives
+    }
+
+    /// Returns amount of datagrams discarded due to errors in their headers.
+    pub fn in_hdr_errors(&self) -> u64 {
+        self.in_hdr_errors
+    }
+
+    /// Returns amount of datagrams discarded due to the invalid destination address.
+    pub fn in_addr_errors(&self) -> u64 {
+        self.in_addr_errors
+    }
+
+    /// Returns amount of valid datagrams discarded, ex. for lack of buffer space.
+    pub fn in_discards(&self) -> u64 {
+        self.in_discards
+    }
+
+    /// Returns amount of datagrams delivered to the upper-layer protocols.
+    pub fn in_delivers(&self) -> u64 {
+        self.in_delivers
+    }
+
+    /// Returns amount of datagrams supplied by the upper-layer protocols for transmission.
+    pub fn out_requests(&self) -> u64 {
+        self.out_requests
+    }
+
+    /// Returns amount of valid outgoing datagrams discarded, ex. for lack of buffer space.
+    pub fn out_discards(&self) -> u64 {
+        self.out_discards
+    }
+
+    /// Returns amount of outgoing datagrams discarded because no route could be found.
+    pub fn out_no_routes(&self) -> u64 {
+        self.out_no_routes
+    }
+
+    /// Returns amount of received fragments needed to be reassembled.
+    pub fn reasm_reqds(&self) -> u64 {
+        self.reasm_reqds
+    }
+
+    /// Returns amount of datagrams successfully reassembled.
+    pub fn reasm_oks(&self) -> u64 {
+        self.reasm_oks
+    }
+
+    /// Returns amount of failures detected by the reassembly algorithm.
+    pub fn reasm_fails(&self) -> u64 {
+        self.reasm_fails
+    }
+
+    /// Returns amount of datagrams successfully fragmented.
+    pub fn frag_oks(&self) -> u64 {
+        self.frag_oks
+    }
+
+    /// Returns amount of datagrams discarded because they needed to be fragmented, but could not be.
+    pub fn frag_fails(&self) -> u64 {
+        self.frag_fails
+    }
+
+    /// Returns amount of datagram fragments generated by fragmentation.
+    pub fn frag_creates(&self) -> u64 {
+        self.frag_creates
+    }
+}
+
+/// ICMP protocol statistics.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct IcmpStats {
+    pub(crate) in_msgs: u64,
+    pub(crate) in_errors: u64,
+    pub(crate) in_dest_unreachs: u64,
+    pub(crate) in_time_excds: u64,
+    pub(crate) in_echos: u64,
+    pub(crate) in_echo_reps: u64,
+    pub(crate) out_msgs: u64,
+    pub(crate) out_errors: u64,
+    pub(crate) out_dest_unreachs: u64,
+    pub(crate) out_time_excds: u64,
+    pub(crate) out_echos: u64,
+    pub(crate) out_echo_reps: u64,
+}
+
+impl IcmpStats {
+    /// Returns amount of messages received, including ones received in error.
+    pub fn in_msgs(&self) -> u64 {
+        self.in_msgs
+    }
+
+    /// Returns amount of messages received with ICMP-specific errors.
+    pub fn in_errors(&self) -> u64 {
+        self.in_errors
+    }
+
+    /// Returns amount of "Destination Unreachable" messages received.
+    pub fn in_dest_unreachs(&self) -> u64 {
+        self.in_dest_unreachs
+    }
+
+    /// Returns amount of "Time Exceeded" messages received.
+    pub fn in_time_excds(&self) -> u64 {
+        self.in_time_excds
+    }
+
+    /// Returns amount of "Echo Request" messages received.
+    pub fn in_echos(&self) -> u64 {
+        self.in_echos
+    }
+
+    /// Returns amount of "Echo Reply" messages received.
+    pub fn in_echo_reps(&self) -> u64 {
+        self.in_echo_reps
+    }
+
+    /// Returns amount of messages attempted to send, including ones sent in error.
+    pub fn out_msgs(&self) -> u64 {
+        self.out_msgs
+    }
+
+    /// Returns amount of messages not sent due to the ICMP-specific problems.
+    pub fn out_errors(&self) -> u64 {
+        self.out_errors
+    }
+
+    /// Returns amount of "Destination Unreachable" messages sent.
+    pub fn out_dest_unreachs(&self) -> u64 {
+        self.out_dest_unreachs
+    }
+
+    /// Returns amount of "Time Exceeded" messages sent.
+    pub fn out_time_excds(&self) -> u64 {
+        self.out_time_excds
+    }
+
+    /// Returns amount of "Echo Request" messages sent.
+    pub fn out_echos(&self) -> u64 {
+        self.out_echos
+    }
+
+    /// Returns amount of "Echo Reply" messages sent.
+    pub fn out_echo_reps(&self) -> u64 {
+        self.out_echo_reps
+    }
+}
+
+/// TCP protocol statistics.
+///
+/// Counters are shared by the IPv4 and IPv6 connections.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TcpStats {
+    pub(crate) active_opens: u64,
+    pub(crate) passive_opens: u64,
+    pub(crate) attempt_fails: u64,
+    pub(crate) estab_resets: u64,
+    pub(crate) curr_estab: u64,
+    pub(crate) in_segs: u64,
+    pub(crate) out_segs: u64,
+    pub(crate) retrans_segs: u64,
+    pub(crate) in_errs: u64,
+    pub(crate) out_rsts: u64,
+    pub(crate) listen_overflows: Option<u64>,
+    pub(crate) listen_drops: Option<u64>,
+}
+
+impl TcpStats {
+    /// Returns amount of connections opened by this host.
+    pub fn active_opens(&self) -> u64 {
+        self.active_opens
+    }
+
+    /// Returns amount of connections accepted by this host.
+    pub fn passive_opens(&self) -> u64 {
+        self.passive_opens
+    }
+
+    /// Returns amount of failed connection attempts.
+    pub fn attempt_fails(&self) -> u64 {
+        self.attempt_fails
+    }
+
+    /// Returns amount of established connections which were reset.
+    pub fn estab_resets(&self) -> u64 {
+        self.estab_resets
+    }
+
+    /// Returns amount of currently established connections.
+    pub fn curr_estab(&self) -> u64 {
+        self.curr_estab
+    }
+
+    /// Returns amount of segments received, including ones received in error.
+    pub fn in_segs(&self) -> u64 {
+        self.in_segs
+    }
+
+    /// Returns amount of segments sent, excluding retransmitted ones.
+    pub fn out_segs(&self) -> u64 {
+        self.out_segs
+    }
+
+    /// Returns amount of segments retransmitted.
+    pub fn retrans_segs(&self) -> u64 {
+        self.retrans_segs
+    }
+
+    /// Returns amount of segments received in error.
+    pub fn in_errs(&self) -> u64 {
+        self.in_errs
+    }
+
+    /// Returns amount of segments sent with the RST flag.
+    pub fn out_rsts(&self) -> u64 {
+        self.out_rsts
+    }
+
+    /// Returns amount of times the listen socket accept queue overflowed.
+    ///
+    /// `None` is returned if `/proc/net/netstat` file is not available.
+    pub fn listen_overflows(&self) -> Option<u64> {
+        self.listen_overflows
+    }
+
+    /// Returns amount of connection requests dropped by the listen sockets.
+    ///
+    /// `None` is returned if `/proc/net/netstat` file is not available.
+    pub fn listen_drops(&self) -> Option<u64> {
+        self.listen_drops
+    }
+}
+
+/// UDP protocol statistics.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UdpStats {
+    pub(crate) in_datagrams: u64,
+    pub(crate) no_ports: u64,
+    pub(crate) in_errors: u64,
+    pub(crate) out_datagrams: u64,
+    pub(crate) rcvbuf_errors: u64,
+    pub(crate) sndbuf_errors: u64,
+}
+
+impl UdpStats {
+    /// Returns amount of datagrams delivered to the UDP users.
+    pub fn in_datagrams(&self) -> u64 {
+        self.in_datagrams
+    }
+
+    /// Returns amount of datagrams received for the ports without listeners.
+    pub fn no_ports(&self) -> u64 {
+        self.no_ports
+    }
+
+    /// Returns amount of datagrams which could not be delivered for other reasons.
+    pub fn in_errors(&self) -> u64 {
+        self.in_errors
+    }
+
+    /// Returns amount of datagrams sent.
+    pub fn out_datagrams(&self) -> u64 {
+        self.out_datagrams
+    }
+
+    /// Returns amount of datagrams dropped because of the full socket receive buffer.
+    pub fn rcvbuf_errors(&self) -> u64 {
+        self.rcvbuf_errors
+    }
+
+    /// Returns amount of datagrams dropped because of the full socket send buffer.
+    pub fn sndbuf_errors(&self) -> u64 {
+        self.sndbuf_errors
+    }
+}
+
+/// Network protocols statistics.
+///
+/// Returned by [protocol_stats] function.
+/// IPv6 statistics are `None` if IPv6 is not available.
+///
+/// [protocol_stats]: ./fn.protocol_stats.html
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ProtocolStats {
+    pub(crate) ip: IpStats,
+    pub(crate) ip6: Option<IpStats>,
+    pub(crate) icmp: IcmpStats,
+    pub(crate) icmp6: Option<IcmpStats>,
+    pub(crate) tcp: TcpStats,
+    pub(crate) udp: UdpStats,
+    pub(crate) udp6: Option<UdpStats>,
+}
+
+impl ProtocolStats {
+    /// Returns IPv4 statistics.
+    pub fn ip(&self) -> &IpStats {
+        &self.ip
+    }
+
+    /// Returns IPv6 statistics.
+    pub fn ip6(&self) -> Option<&IpStats> {
+        self.ip6.as_ref()
+    }
+
+    /// Returns ICMP statistics.
+    pub fn icmp(&self) -> &IcmpStats {
+        &self.icmp
+    }
+
+    /// Returns ICMPv6 statistics.
+    pub fn icmp6(&self) -> Option<&IcmpStats> {
+        self.icmp6.as_ref()
+    }
+
+    /// Returns TCP statistics.
+    pub fn tcp(&self) -> &TcpStats {
+        &self.tcp
+    }
+
+    /// Returns UDP over IPv4 statistics.
+    pub fn udp(&self) -> &UdpStats {
+        &self.udp
+    }
+
+    /// Returns UDP over IPv6 statistics.
+    pub fn udp6(&self) -> Option<&UdpStats> {
+        self.udp6.as_ref()
+    }
+}
+
+/// Returns network [protocols statistics] since the system boot.
+///
+/// Statistics are loaded from the `/proc/net/snmp`, `/proc/net/snmp6`
+/// and `/proc/net/netstat` files, and they are related
+/// to the network namespace of the current process.
+///
+/// [protocols statistics]: ./struct.ProtocolStats.html
+#[cfg(target_os = "linux")]
+pub async fn protocol_stats() -> Result<ProtocolStats> {
+    sys::protocol_stats().await
+}
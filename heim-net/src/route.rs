@@ -0,0 +1,142 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use crate::sys;
+
+bitflags::bitflags! {
+    /// Routing table entry flags.
+    ///
+    /// See [Route::flags] method, which returns that structure.
+    ///
+    /// [Route::flags]: ./struct.Route.html#method.flags
+    pub struct RouteFlags: u32 {
+        // Values are matching the Linux `RTF_*` constants
+
+        /// Route is usable.
+        const UP = 0x0001;
+        /// Destination is reachable via gateway.
+        const GATEWAY = 0x0002;
+        /// Destination is a host, not a network.
+        const HOST = 0x0004;
+        /// Route was created dynamically, ex. by redirect.
+        const DYNAMIC = 0x0010;
+        /// Route was modified dynamically, ex. by redirect.
+        const MODIFIED = 0x0020;
+        /// Destination is unreachable, packets are rejected.
+        const REJECT = 0x0200;
+    }
+}
+
+/// Routing table entry.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Route {
+    pub(crate) destination: IpAddr,
+    pub(crate) prefix_len: u8,
+    pub(crate) gateway: Option<IpAddr>,
+    pub(crate) interface: String,
+    pub(crate) metric: u32,
+    pub(crate) flags: RouteFlags,
+}
+
+impl Route {
+    /// Returns destination network address.
+    pub fn destination(&self) -> IpAddr {
+        self.destination
+    }
+
+    /// Returns destination network mask.
+    pub fn mask(&self) -> IpAddr {
+        match self.destination {
+            IpAddr::V4(..) => {
+                let mask = (!0u32)
+                    .checked_shl(32 - u32::from(self.prefix_len))
+                    .unwrap_or(0);
+                IpAddr::V4(Ipv4Addr::from(mask))
+            }
+            IpAddr::V6(..) => {
+                let mask = (!0u128)
+                    .checked_shl(128 - u32::from(self.prefix_len))
+                    .unwrap_or(0);
+                IpAddr::V6(Ipv6Addr::from(mask))
+            }
+        }
+    }
+
+    /// Returns destination network prefix length.
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns gateway address, if destination is not reachable directly.
+    pub fn gateway(&self) -> Option<IpAddr> {
+        self.gateway
+    }
+
+    /// Returns name of the network interface used for this route.
+    pub fn interface(&self) -> &str {
+        self.interface.as_str()
+    }
+
+    /// Returns route metric, routes with the lower metric are preferred.
+    pub fn metric(&self) -> u32 {
+        self.metric
+    }
+
+    /// Returns route flags.
+    pub fn flags(&self) -> RouteFlags {
+        self.flags
+    }
+
+    /// Returns `bool` indicating whether this route is a default one.
+    pub fn is_default(&self) -> bool {
+        self.prefix_len == 0 && self.destination.is_unspecified()
+    }
+}
+
+/// Returns a stream over the IPv4 and IPv6 [routing table] entries.
+///
+/// ## Compatibility
+///
+/// Implemented only for Linux for now. For other platforms will return an empty stream.
+///
+/// [routing table]: ./struct.Route.html
+pub fn routes() -> impl Stream<Item = Result<Route>> {
+    sys::routes()
+}
+
+/// Returns default [route] via gateway if there is any.
+///
+/// If there are multiple default routes, IPv4 ones are preferred,
+/// and then the one with the lowest metric is returned.
+///
+/// ## Compatibility
+///
+/// Implemented only for Linux for now. For other platforms will return `None`.
+///
+/// [route]: ./struct.Route.html
+pub async fn default_gateway() -> Result<Option<Route>> {
+    let routes = routes();
+    rt::pin!(routes);
+
+    let mut default: Option<Route> = None;
+    while let Some(route) = routes.next().await {
+        let route = route?;
+        if !route.is_default()
+            || route.gateway.is_none()
+            || !route.flags.contains(RouteFlags::UP)
+            || route.flags.contains(RouteFlags::REJECT)
+        {
+            continue;
+        }
+
+        let key = |route: &Route| (route.destination.is_ipv6(), route.metric);
+        default = match default {
+            Some(current) if key(&current) <= key(&route) => Some(current),
+            _ => Some(route),
+        };
+    }
+
+    Ok(default)
+}
@@ -0,0 +1,250 @@
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+
+use heim_common::prelude::*;
+use heim_common::utils::iter::*;
+use heim_runtime as rt;
+
+use crate::{ConnectionKind, ConnectionState, Protocol};
+
+#[derive(Debug)]
+pub struct Connection {
+    protocol: Protocol,
+    local_address: Option<SocketAddr>,
+    remote_address: Option<SocketAddr>,
+    path: Option<PathBuf>,
+    state: ConnectionState,
+    inode: u64,
+}
+
+impl Connection {
+    pub fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    pub fn local_address(&self) -> Option<SocketAddr> {
+        self.local_address
+    }
+
+    pub fn remote_address(&self) -> Option<SocketAddr> {
+        self.remote_address
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_ref().map(AsRef::as_ref)
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    pub fn inode(&self) -> u64 {
+        self.inode
+    }
+
+    /// Parses line from the `/proc/net/{tcp,tcp6,udp,udp6}` files.
+    ///
+    /// See `tcp4_seq_show` and `udp4_format_sock` functions in the Linux sources for format details.
+    fn parse_inet(line: &str, protocol: Protocol) -> Result<Connection> {
+        let mut parts = line.split_whitespace().skip(1);
+        let local_address = parse_socket_addr(parts.try_next()?)?;
+        let remote_address = parse_socket_addr(parts.try_next()?)?;
+        let state = parts.try_next()?;
+        let mut parts = parts.skip(5);
+        let inode = parts.try_parse_next()?;
+
+        let state = match protocol {
+            Protocol::Tcp | Protocol::Tcp6 => parse_tcp_state(state)?,
+            _ => ConnectionState::None,
+        };
+
+        let remote_address = if remote_address.ip().is_unspecified() && remote_address.port() == 0 {
+            None
+        } else {
+            Some(remote_address)
+        };
+
+        Ok(Connection {
+            protocol,
+            local_address: Some(local_address),
+            remote_address,
+            path: None,
+            state,
+            inode,
+        })
+    }
+
+    /// Parses line from the `/proc/net/unix` file.
+    ///
+    /// See `unix_seq_show` function in the Linux sources for format details.
+    fn parse_unix(line: &str) -> Result<Connection> {
+        let mut parts = line.split_whitespace().skip(6);
+        let inode = parts.try_parse_next()?;
+        let path = parts.collect::<Vec<_>>().join(" ");
+
+        Ok(Connection {
+            protocol: Protocol::Unix,
+            local_address: None,
+            remote_address: None,
+            path: if path.is_empty() {
+                None
+            } else {
+                Some(PathBuf::from(path))
+            },
+            state: ConnectionState::None,
+            inode,
+        })
+    }
+}
+
+fn invalid_data() -> Error {
+    Error::from(io::Error::from(io::ErrorKind::InvalidData))
+}
+
+/// Parses `"0100007F:0050"`-like address.
+///
+/// IP address is printed as a sequence of 32-bit words in the host byte order,
+/// while port is printed in the network byte order.
+fn parse_socket_addr(value: &str) -> Result<SocketAddr> {
+    let mut parts = value.splitn(2, ':');
+    let ip = parts.try_next()?;
+    let port = u16::from_str_radix(parts.try_next()?, 16)?;
+
+    let ip = match ip.len() {
+        8 => {
+            let word = u32::from_str_radix(ip, 16)?;
+
+            IpAddr::V4(Ipv4Addr::from(word.to_ne_bytes()))
+        }
+        32 => {
+            let mut octets = [0u8; 16];
+            for (idx, chunk) in octets.chunks_mut(4).enumerate() {
+                let word = u32::from_str_radix(&ip[idx * 8..idx * 8 + 8], 16)?;
+                chunk.copy_from_slice(&word.to_ne_bytes());
+            }
+
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        _ => return Err(invalid_data()),
+    };
+
+    Ok(SocketAddr::new(ip, port))
+}
+
+/// See `include/net/tcp_states.h` in the Linux sources.
+fn parse_tcp_state(value: &str) -> Result<ConnectionState> {
+    match u8::from_str_radix(value, 16)? {
+        0x01 => Ok(ConnectionState::Established),
+        0x02 => Ok(ConnectionState::SynSent),
+        0x03 | 0x0C => Ok(ConnectionState::SynRecv),
+        0x04 => Ok(ConnectionState::FinWait1),
+        0x05 => Ok(ConnectionState::FinWait2),
+        0x06 => Ok(ConnectionState::TimeWait),
+        0x07 => Ok(ConnectionState::Close),
+        0x08 => Ok(ConnectionState::CloseWait),
+        0x09 => Ok(ConnectionState::LastAck),
+        0x0A => Ok(ConnectionState::Listen),
+        0x0B => Ok(ConnectionState::Closing),
+        other => Err(invalid_data().with_message(format!("Unknown TCP state {}", other))),
+    }
+}
+
+fn table(protocol: Protocol) -> impl Stream<Item = Result<Connection>> {
+    let path = match protocol {
+        Protocol::Tcp => "net/tcp",
+        Protocol::Tcp6 => "net/tcp6",
+        Protocol::Udp => "net/udp",
+        Protocol::Udp6 => "net/udp6",
+        Protocol::Unix => "net/unix",
+    };
+
+    rt::fs::read_lines(rt::fs::proc_path(path))
+        // Table might be missing if protocol is not supported by kernel,
+        // ex. when IPv6 is disabled
+        .map(|result| match result {
+            Ok(lines) => Ok(Some(lines)),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        })
+        .into_stream()
+        .try_filter_map(future::ok)
+        .try_flatten()
+        .skip(1)
+        .map_err(Error::from)
+        .and_then(move |line| match protocol {
+            Protocol::Unix => future::ready(Connection::parse_unix(&line)),
+            _ => future::ready(Connection::parse_inet(&line, protocol)),
+        })
+}
+
+pub fn connections(kind: ConnectionKind) -> impl Stream<Item = Result<Connection>> {
+    stream::iter(kind.protocols())
+        .map(|protocol| table(*protocol))
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+    use std::path::Path;
+
+    use super::Connection;
+    use crate::{ConnectionState, Protocol};
+
+    #[test]
+    fn test_parse_tcp() {
+        let line = "   0: 0100007F:0277 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 21416 1 0000000000000000 100 0 0 10 0";
+        let conn = Connection::parse_inet(line, Protocol::Tcp).unwrap();
+
+        assert_eq!(
+            conn.local_address(),
+            Some(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 631))
+        );
+        assert_eq!(conn.remote_address(), None);
+        assert_eq!(conn.state(), ConnectionState::Listen);
+        assert_eq!(conn.inode(), 21416);
+    }
+
+    #[test]
+    fn test_parse_tcp6() {
+        let line = "   1: 00000000000000000000000001000000:0277 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 21415 1 0000000000000000 100 0 0 10 0";
+        let conn = Connection::parse_inet(line, Protocol::Tcp6).unwrap();
+
+        assert_eq!(
+            conn.local_address(),
+            Some(SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 631))
+        );
+        assert_eq!(conn.remote_address(), None);
+        assert_eq!(conn.inode(), 21415);
+    }
+
+    #[test]
+    fn test_parse_udp() {
+        let line = " 3178: 0100007F:0035 0100007F:A1C2 01 00000000:00000000 00:00000000 00000000   101        0 18622 2 0000000000000000 0";
+        let conn = Connection::parse_inet(line, Protocol::Udp).unwrap();
+
+        assert_eq!(
+            conn.remote_address(),
+            Some(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 41410))
+        );
+        assert_eq!(conn.state(), ConnectionState::None);
+    }
+
+    #[test]
+    fn test_parse_unix() {
+        let line =
+            "0000000000000000: 00000002 00000000 00010000 0001 01 24680 /run/systemd/private";
+        let conn = Connection::parse_unix(line).unwrap();
+
+        assert_eq!(conn.protocol(), Protocol::Unix);
+        assert_eq!(conn.path(), Some(Path::new("/run/systemd/private")));
+        assert_eq!(conn.inode(), 24680);
+
+        let line = "0000000000000000: 00000003 00000000 00000000 0001 03  9961";
+        let conn = Connection::parse_unix(line).unwrap();
+
+        assert_eq!(conn.path(), None);
+        assert_eq!(conn.inode(), 9961);
+    }
+}
@@ -1,3 +1,5 @@
+use std::io;
+use std::os::unix::fs::MetadataExt as _;
 use std::str::FromStr;
 
 use heim_common::prelude::*;
@@ -6,6 +8,8 @@ use heim_common::utils::iter::*;
 use heim_common::Pid;
 use heim_runtime as rt;
 
+use crate::os::linux::Statistics;
+
 #[derive(Debug)]
 pub struct IoCounters {
     interface: String,
@@ -22,9 +26,11 @@ pub struct IoCounters {
     tx_errs: u64,
     tx_drop: u64,
     tx_fifo: u64,
-    tx_frame: u64,
+    tx_colls: u64,
+    tx_carrier: u64,
     tx_compressed: u64,
-    tx_multicast: u64,
+    // Set if counters were loaded for the network namespace of this process
+    pid: Option<Pid>,
 }
 
 impl IoCounters {
@@ -63,11 +69,110 @@ impl IoCounters {
     pub fn drop_sent(&self) -> u64 {
         self.tx_drop
     }
+
+    pub fn fifo_recv(&self) -> u64 {
+        self.rx_fifo
+    }
+
+    pub fn frame_recv(&self) -> u64 {
+        self.rx_frame
+    }
+
+    pub fn compressed_recv(&self) -> u64 {
+        self.rx_compressed
+    }
+
+    pub fn multicast_recv(&self) -> u64 {
+        self.rx_multicast
+    }
+
+    pub fn fifo_sent(&self) -> u64 {
+        self.tx_fifo
+    }
+
+    pub fn collisions(&self) -> u64 {
+        self.tx_colls
+    }
+
+    pub fn carrier_sent(&self) -> u64 {
+        self.tx_carrier
+    }
+
+    pub fn compressed_sent(&self) -> u64 {
+        self.tx_compressed
+    }
+
+    pub async fn statistics(&self) -> Result<Statistics> {
+        // `/sys/class/net` is related to the network namespace of the current process,
+        // which might differ from the one these counters were loaded for
+        if let Some(pid) = self.pid {
+            if net_namespace(&pid.to_string()).await? != net_namespace("self").await? {
+                return Err(
+                    Error::from(io::Error::from(io::ErrorKind::Other)).with_message(format!(
+                        "Statistics are not available for the network namespace of process {}",
+                        pid
+                    )),
+                );
+            }
+        }
+
+        // Interface might be removed already
+        let path = rt::fs::sys_path(format!("class/net/{}/statistics", self.interface));
+        if let Err(e) = rt::fs::metadata(&path).await {
+            return Err(Error::from(e).with_file(path));
+        }
+
+        let statistic = |name: &'static str| read_statistic(&self.interface, name);
+
+        Ok(Statistics {
+            rx_crc_errors: statistic("rx_crc_errors").await?,
+            rx_frame_errors: statistic("rx_frame_errors").await?,
+            rx_length_errors: statistic("rx_length_errors").await?,
+            rx_missed_errors: statistic("rx_missed_errors").await?,
+            rx_over_errors: statistic("rx_over_errors").await?,
+            rx_fifo_errors: statistic("rx_fifo_errors").await?,
+            rx_nohandler: statistic("rx_nohandler").await?,
+            tx_aborted_errors: statistic("tx_aborted_errors").await?,
+            tx_carrier_errors: statistic("tx_carrier_errors").await?,
+            tx_fifo_errors: statistic("tx_fifo_errors").await?,
+            tx_heartbeat_errors: statistic("tx_heartbeat_errors").await?,
+            tx_window_errors: statistic("tx_window_errors").await?,
+            collisions: statistic("collisions").await?,
+        })
+    }
+}
+
+/// Returns the inode of the `/proc/<process>/ns/net` file,
+/// which identifies the network namespace of the process.
+async fn net_namespace(process: &str) -> Result<u64> {
+    let path = rt::fs::proc_path(format!("{}/ns/net", process));
+
+    match rt::fs::metadata(&path).await {
+        Ok(metadata) => Ok(metadata.ino()),
+        Err(e) => Err(Error::from(e).with_file(path)),
+    }
+}
+
+/// Reads `/sys/class/net/<interface>/statistics/<name>` counter.
+///
+/// Counters which are not supported by the running kernel are returned as `None`.
+async fn read_statistic(interface: &str, name: &str) -> Result<Option<u64>> {
+    let path = rt::fs::sys_path(format!("class/net/{}/statistics/{}", interface, name));
+
+    match rt::fs::read_to_string(&path).await {
+        Ok(contents) => Ok(Some(contents.trim().parse()?)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(Error::from(e).with_file(path)),
+    }
 }
 
 impl FromStr for IoCounters {
     type Err = Error;
 
+    // Columns are:
+    //   rx: bytes packets errs drop fifo frame compressed multicast
+    //   tx: bytes packets errs drop fifo colls carrier compressed
+    //
     // Example:
     // wlp3s0: 550608563  390526    0    0    0 61962          0         0 14822919  103337    0    0    0     0       0
     // 0
@@ -98,15 +203,16 @@ impl FromStr for IoCounters {
             tx_errs: parts.try_parse_next()?,
             tx_drop: parts.try_parse_next()?,
             tx_fifo: parts.try_parse_next()?,
-            tx_frame: parts.try_parse_next()?,
+            tx_colls: parts.try_parse_next()?,
+            tx_carrier: parts.try_parse_next()?,
             tx_compressed: parts.try_parse_next()?,
-            tx_multicast: parts.try_parse_next()?,
+            pid: None,
         })
     }
 }
 
 pub fn io_counters() -> impl Stream<Item = Result<IoCounters>> {
-    rt::fs::read_lines("/proc/net/dev")
+    rt::fs::read_lines(rt::fs::proc_path("net/dev"))
         .try_flatten_stream()
         .skip(2)
         .map_err(Error::from)
@@ -114,9 +220,46 @@ pub fn io_counters() -> impl Stream<Item = Result<IoCounters>> {
 }
 
 pub fn io_counters_for_pid(pid: Pid) -> impl Stream<Item = Result<IoCounters>> {
-    rt::fs::read_lines(format!("/proc/{}/net/dev", pid))
+    rt::fs::read_lines(rt::fs::proc_path(format!("{}/net/dev", pid)))
         .try_flatten_stream()
         .skip(2)
         .map_err(Error::from)
         .and_then(|line| async move { IoCounters::from_str(&line) })
+        .map_ok(move |counters| IoCounters {
+            pid: Some(pid),
+            ..counters
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use heim_common::units::information;
+
+    use super::IoCounters;
+
+    #[test]
+    fn test_parse_io_counters() {
+        let line = "  eth0: 5506085 3905 1 2 3 4 5 6 1482291 1033 7 8 9 10 11 12";
+        let counters = IoCounters::from_str(line).unwrap();
+
+        assert_eq!(counters.interface(), "eth0");
+        assert_eq!(counters.bytes_recv().get::<information::byte>(), 5_506_085);
+        assert_eq!(counters.packets_recv(), 3905);
+        assert_eq!(counters.errors_recv(), 1);
+        assert_eq!(counters.drop_recv(), 2);
+        assert_eq!(counters.fifo_recv(), 3);
+        assert_eq!(counters.frame_recv(), 4);
+        assert_eq!(counters.compressed_recv(), 5);
+        assert_eq!(counters.multicast_recv(), 6);
+        assert_eq!(counters.bytes_sent().get::<information::byte>(), 1_482_291);
+        assert_eq!(counters.packets_sent(), 1033);
+        assert_eq!(counters.errors_sent(), 7);
+        assert_eq!(counters.drop_sent(), 8);
+        assert_eq!(counters.fifo_sent(), 9);
+        assert_eq!(counters.collisions(), 10);
+        assert_eq!(counters.carrier_sent(), 11);
+        assert_eq!(counters.compressed_sent(), 12);
+    }
 }
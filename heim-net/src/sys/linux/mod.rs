@@ -1,3 +1,11 @@
+mod connections;
 mod counters;
+mod nic;
+mod protocol;
+mod route;
 
+pub use self::connections::*;
 pub use self::counters::*;
+pub use self::nic::*;
+pub use self::protocol::*;
+pub use self::route::*;
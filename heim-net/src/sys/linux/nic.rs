@@ -0,0 +1,172 @@
+use std::io;
+use std::path::PathBuf;
+
+use heim_common::prelude::*;
+use heim_common::units::{information_rate, InformationRate};
+use heim_runtime as rt;
+
+use crate::os::linux::{Duplex, OperState};
+use crate::sys::Nic;
+
+/// Returns the link name for the interface `name`.
+///
+/// IPv4 address aliases are reported as `<link>:<label>` interfaces,
+/// while there is only the `/sys/class/net/<link>/` directory for all of them.
+fn link_name(name: &str) -> &str {
+    match name.find(':') {
+        Some(idx) => &name[..idx],
+        None => name,
+    }
+}
+
+/// Returns path to the `attribute` in the `/sys/class/net/<link>/` directory.
+fn attribute_path(name: &str, attribute: &str) -> PathBuf {
+    rt::fs::sys_path(format!("class/net/{}/{}", link_name(name), attribute))
+}
+
+/// Reads the `attribute` file from the `/sys/class/net/<link>/` directory.
+///
+/// Some attributes can't be read while interface is down,
+/// kernel returns `EINVAL` error in that case, which is mapped into `None`.
+async fn read_attribute(name: &str, attribute: &str) -> Result<Option<String>> {
+    let path = attribute_path(name, attribute);
+
+    match rt::fs::read_to_string(&path).await {
+        Ok(contents) => Ok(Some(contents.trim().to_string())),
+        Err(e) if e.raw_os_error() == Some(libc::EINVAL) => Ok(None),
+        Err(e) => Err(Error::from(e).with_file(path)),
+    }
+}
+
+/// Reads the `attribute` file, which should be readable no matter of the interface state.
+async fn read_required(name: &str, attribute: &str) -> Result<String> {
+    match read_attribute(name, attribute).await? {
+        Some(value) => Ok(value),
+        None => {
+            let path = attribute_path(name, attribute);
+            Err(Error::from(io::Error::from(io::ErrorKind::InvalidData)).with_file(path))
+        }
+    }
+}
+
+pub async fn mtu(name: &str) -> Result<u32> {
+    let value = read_required(name, "mtu").await?;
+
+    Ok(value.parse()?)
+}
+
+fn parse_speed(value: &str) -> Result<Option<InformationRate>> {
+    // Unknown speed is reported as `-1`
+    match value.parse::<i64>()? {
+        speed if speed > 0 => Ok(Some(InformationRate::new::<
+            information_rate::megabit_per_second,
+        >(speed as u64))),
+        _ => Ok(None),
+    }
+}
+
+fn parse_duplex(value: &str) -> Duplex {
+    match value {
+        "full" => Duplex::Full,
+        "half" => Duplex::Half,
+        _ => Duplex::Unknown,
+    }
+}
+
+fn parse_operstate(value: &str) -> OperState {
+    match value {
+        "notpresent" => OperState::NotPresent,
+        "down" => OperState::Down,
+        "lowerlayerdown" => OperState::LowerLayerDown,
+        "testing" => OperState::Testing,
+        "dormant" => OperState::Dormant,
+        "up" => OperState::Up,
+        _ => OperState::Unknown,
+    }
+}
+
+impl Nic {
+    pub async fn speed(&self) -> Result<Option<InformationRate>> {
+        match read_attribute(self.name(), "speed").await? {
+            Some(value) => parse_speed(&value),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn duplex(&self) -> Result<Duplex> {
+        match read_attribute(self.name(), "duplex").await {
+            Ok(Some(value)) => Ok(parse_duplex(&value)),
+            Ok(None) => Ok(Duplex::Unknown),
+            // Virtual interfaces might have no `duplex` attribute at all
+            Err(e) if e.as_inner().kind() == io::ErrorKind::NotFound => Ok(Duplex::Unknown),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn operstate(&self) -> Result<OperState> {
+        match read_attribute(self.name(), "operstate").await? {
+            Some(value) => Ok(parse_operstate(&value)),
+            None => Ok(OperState::Unknown),
+        }
+    }
+
+    pub async fn carrier(&self) -> Result<Option<bool>> {
+        match read_attribute(self.name(), "carrier").await? {
+            Some(value) => Ok(Some(value.parse::<u8>()? != 0)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn tx_queue_len(&self) -> Result<u64> {
+        let value = read_required(self.name(), "tx_queue_len").await?;
+
+        Ok(value.parse()?)
+    }
+
+    pub async fn driver(&self) -> Result<Option<String>> {
+        let path = attribute_path(self.name(), "device/driver");
+
+        match rt::fs::read_link(&path).await {
+            Ok(driver) => Ok(driver
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(Error::from(e).with_file(path)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::information_rate;
+
+    use super::{link_name, parse_duplex, parse_operstate, parse_speed};
+    use crate::os::linux::{Duplex, OperState};
+
+    #[test]
+    fn test_link_name() {
+        assert_eq!(link_name("eth0"), "eth0");
+        assert_eq!(link_name("eth0:1"), "eth0");
+        assert_eq!(link_name("br-lan:backup"), "br-lan");
+    }
+
+    #[test]
+    fn test_parse_speed() {
+        let speed = parse_speed("1000").unwrap().unwrap();
+        assert_eq!(speed.get::<information_rate::megabit_per_second>(), 1000);
+
+        assert!(parse_speed("-1").unwrap().is_none());
+        assert!(parse_speed("").is_err());
+    }
+
+    #[test]
+    fn test_parse_states() {
+        assert_eq!(parse_duplex("full"), Duplex::Full);
+        assert_eq!(parse_duplex("half"), Duplex::Half);
+        assert_eq!(parse_duplex("unknown"), Duplex::Unknown);
+
+        assert_eq!(parse_operstate("up"), OperState::Up);
+        assert_eq!(parse_operstate("lowerlayerdown"), OperState::LowerLayerDown);
+        assert_eq!(parse_operstate("unknown"), OperState::Unknown);
+    }
+}
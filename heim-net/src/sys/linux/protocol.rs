@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+use std::io;
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use crate::os::linux::{IcmpStats, IpStats, ProtocolStats, TcpStats, UdpStats};
+
+/// Counters loaded from the `/proc/net/` files,
+/// keyed by the section name joined with the counter name, ex. `TcpRetransSegs`.
+#[derive(Debug, Default)]
+struct Counters {
+    values: HashMap<String, u64>,
+}
+
+impl Counters {
+    /// Parses files with the section header line followed by the values line,
+    /// as `/proc/net/snmp` and `/proc/net/netstat` are.
+    fn parse_table(&mut self, contents: &str, file: &'static str) -> Result<()> {
+        let mut lines = contents.lines();
+
+        while let Some(header) = lines.next() {
+            let values = match lines.next() {
+                Some(values) => values,
+                None => return Err(Error::missing_key(header.to_string(), file)),
+            };
+
+            let mut names = header.split_whitespace();
+            let mut values = values.split_whitespace();
+            let section = match (names.next(), values.next()) {
+                (Some(left), Some(right)) if left == right => left.trim_end_matches(':'),
+                _ => return Err(Error::missing_key(header.to_string(), file)),
+            };
+
+            for (name, value) in names.zip(values) {
+                // Few values, ex. `Tcp: MaxConn`, might be negative,
+                // they are not counters and are not used anyway
+                if let Ok(value) = value.parse::<u64>() {
+                    let _ = self.values.insert(format!("{}{}", section, name), value);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses files with the one counter per line, as `/proc/net/snmp6` is.
+    fn parse_flat(&mut self, contents: &str) -> Result<()> {
+        for line in contents.lines() {
+            let mut parts = line.split_whitespace();
+            if let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+                let _ = self.values.insert(name.to_string(), value.parse()?);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get(&self, prefix: &str, name: &str) -> Result<u64> {
+        let key = format!("{}{}", prefix, name);
+        match self.values.get(&key) {
+            Some(value) => Ok(*value),
+            None => {
+                let file = match prefix {
+                    "TcpExt" => "/proc/net/netstat",
+                    _ if prefix.ends_with('6') => "/proc/net/snmp6",
+                    _ => "/proc/net/snmp",
+                };
+                Err(Error::missing_key(key, file))
+            }
+        }
+    }
+
+    fn has(&self, prefix: &str) -> bool {
+        self.values.keys().any(|key| key.starts_with(prefix))
+    }
+
+    fn ip(&self, prefix: &str) -> Result<IpStats> {
+        Ok(IpStats {
+            in_receives: self.get(prefix, "InReceives")?,
+            in_hdr_errors: self.get(prefix, "InHdrErrors")?,
+            in_addr_errors: self.get(prefix, "InAddrErrors")?,
+            in_discards: self.get(prefix, "InDiscards")?,
+            in_delivers: self.get(prefix, "InDelivers")?,
+            out_requests: self.get(prefix, "OutRequests")?,
+            out_discards: self.get(prefix, "OutDiscards")?,
+            out_no_routes: self.get(prefix, "OutNoRoutes")?,
+            reasm_reqds: self.get(prefix, "ReasmReqds")?,
+            reasm_oks: self.get(prefix, "ReasmOKs")?,
+            reasm_fails: self.get(prefix, "ReasmFails")?,
+            frag_oks: self.get(prefix, "FragOKs")?,
+            frag_fails: self.get(prefix, "FragFails")?,
+            frag_creates: self.get(prefix, "FragCreates")?,
+        })
+    }
+
+    // ICMPv6 counters are using `EchoReplies` name instead of `EchoReps`
+    fn icmp(&self, prefix: &str, echo_reps: &str) -> Result<IcmpStats> {
+        Ok(IcmpStats {
+            in_msgs: self.get(prefix, "InMsgs")?,
+            in_errors: self.get(prefix, "InErrors")?,
+            in_dest_unreachs: self.get(prefix, "InDestUnreachs")?,
+            in_time_excds: self.get(prefix, "InTimeExcds")?,
+            in_echos: self.get(prefix, "InEchos")?,
+            in_echo_reps: self.get(prefix, &format!("In{}", echo_reps))?,
+            out_msgs: self.get(prefix, "OutMsgs")?,
+            out_errors: self.get(prefix, "OutErrors")?,
+            out_dest_unreachs: self.get(prefix, "OutDestUnreachs")?,
+            out_time_excds: self.get(prefix, "OutTimeExcds")?,
+            out_echos: self.get(prefix, "OutEchos")?,
+            out_echo_reps: self.get(prefix, &format!("Out{}", echo_reps))?,
+        })
+    }
+
+    fn tcp(&self) -> Result<TcpStats> {
+        Ok(TcpStats {
+            active_opens: self.get("Tcp", "ActiveOpens")?,
+            passive_opens: self.get("Tcp", "PassiveOpens")?,
+            attempt_fails: self.get("Tcp", "AttemptFails")?,
+            estab_resets: self.get("Tcp", "EstabResets")?,
+            curr_estab: self.get("Tcp", "CurrEstab")?,
+            in_segs: self.get("Tcp", "InSegs")?,
+            out_segs: self.get("Tcp", "OutSegs")?,
+            retrans_segs: self.get("Tcp", "RetransSegs")?,
+            in_errs: self.get("Tcp", "InErrs")?,
+            out_rsts: self.get("Tcp", "OutRsts")?,
+            // `/proc/net/netstat` file is optional
+            listen_overflows: self.get("TcpExt", "ListenOverflows").ok(),
+            listen_drops: self.get("TcpExt", "ListenDrops").ok(),
+        })
+    }
+
+    fn udp(&self, prefix: &str) -> Result<UdpStats> {
+        Ok(UdpStats {
+            in_datagrams: self.get(prefix, "InDatagrams")?,
+            no_ports: self.get(prefix, "NoPorts")?,
+            in_errors: self.get(prefix, "InErrors")?,
+            out_datagrams: self.get(prefix, "OutDatagrams")?,
+            rcvbuf_errors: self.get(prefix, "RcvbufErrors")?,
+            sndbuf_errors: self.get(prefix, "SndbufErrors")?,
+        })
+    }
+
+    fn into_stats(self) -> Result<ProtocolStats> {
+        let ip6 = self.has("Ip6");
+
+        Ok(ProtocolStats {
+            ip: self.ip("Ip")?,
+            ip6: if ip6 { Some(self.ip("Ip6")?) } else { None },
+            icmp: self.icmp("Icmp", "EchoReps")?,
+            icmp6: if ip6 {
+                Some(self.icmp("Icmp6", "EchoReplies")?)
+            } else {
+                None
+            },
+            tcp: self.tcp()?,
+            udp: self.udp("Udp")?,
+            udp6: if ip6 { Some(self.udp("Udp6")?) } else { None },
+        })
+    }
+}
+
+/// Reads the optional `/proc/net/` file.
+async fn read_optional(path: &'static str) -> Result<Option<String>> {
+    let path = rt::fs::proc_path(path);
+
+    match rt::fs::read_to_string(&path).await {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(Error::from(e).with_file(path)),
+    }
+}
+
+pub async fn protocol_stats() -> Result<ProtocolStats> {
+    let mut counters = Counters::default();
+
+    let path = rt::fs::proc_path("net/snmp");
+    let contents = rt::fs::read_to_string(&path)
+        .await
+        .map_err(|e| Error::from(e).with_file(path))?;
+    counters.parse_table(&contents, "/proc/net/snmp")?;
+
+    if let Some(contents) = read_optional("net/netstat").await? {
+        counters.parse_table(&contents, "/proc/net/netstat")?;
+    }
+    // Missing if IPv6 is disabled
+    if let Some(contents) = read_optional("net/snmp6").await? {
+        counters.parse_flat(&contents)?;
+    }
+
+    counters.into_stats()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Counters;
+
+    static SNMP: &str = "\
+Ip: Forwarding DefaultTTL InReceives InHdrErrors InAddrErrors ForwDatagrams InUnknownProtos InDiscards InDelivers OutRequests OutDiscards OutNoRoutes ReasmTimeout ReasmReqds ReasmOKs ReasmFails FragOKs FragFails FragCreates
+Ip: 2 64 110735 1 2 0 0 3 110735 86024 4 5 0 6 7 8 9 10 11
+Icmp: InMsgs InErrors InCsumErrors InDestUnreachs InTimeExcds InParmProbs InSrcQuenchs InRedirects InEchos InEchoReps InTimestamps InTimestampReps InAddrMasks InAddrMaskReps OutMsgs OutErrors OutDestUnreachs OutTimeExcds OutParmProbs OutSrcQuenchs OutRedirects OutEchos OutEchoReps OutTimestamps OutTimestampReps OutAddrMasks OutAddrMaskReps
+Icmp: 45 1 0 40 2 0 0 0 3 4 0 0 0 0 50 0 41 0 0 0 0 5 6 0 0 0 0
+Tcp: RtoAlgorithm RtoMin RtoMax MaxConn ActiveOpens PassiveOpens AttemptFails EstabResets CurrEstab InSegs OutSegs RetransSegs InErrs OutRsts InCsumErrors
+Tcp: 1 200 120000 -1 133 13 1 74 2 110491 85971 6 0 180 0
+Udp: InDatagrams NoPorts InErrors OutDatagrams RcvbufErrors SndbufErrors InCsumErrors IgnoredMulti
+Udp: 244 3 2 244 1 0 0 0
+";
+
+    static NETSTAT: &str = "\
+TcpExt: SyncookiesSent SyncookiesRecv ListenOverflows ListenDrops
+TcpExt: 0 0 12 15
+IpExt: InNoRoutes InTruncatedPkts
+IpExt: 0 0
+";
+
+    static SNMP6: &str = "\
+Ip6InReceives                   	3
+Ip6InHdrErrors                  	0
+Ip6InAddrErrors                 	0
+Ip6InDiscards                   	0
+Ip6InDelivers                   	2
+Ip6OutRequests                  	5
+Ip6OutDiscards                  	0
+Ip6OutNoRoutes                  	0
+Ip6ReasmReqds                   	0
+Ip6ReasmOKs                     	0
+Ip6ReasmFails                   	0
+Ip6FragOKs                      	0
+Ip6FragFails                    	0
+Ip6FragCreates                  	0
+Icmp6InMsgs                     	0
+Icmp6InErrors                   	0
+Icmp6OutMsgs                    	5
+Icmp6OutErrors                  	0
+Icmp6InDestUnreachs             	0
+Icmp6InTimeExcds                	0
+Icmp6InEchos                    	0
+Icmp6InEchoReplies              	1
+Icmp6OutDestUnreachs            	0
+Icmp6OutTimeExcds               	0
+Icmp6OutEchos                   	0
+Icmp6OutEchoReplies             	2
+Udp6InDatagrams                 	7
+Udp6NoPorts                     	0
+Udp6InErrors                    	0
+Udp6OutDatagrams                	7
+Udp6RcvbufErrors                	0
+Udp6SndbufErrors                	0
+";
+
+    #[test]
+    fn test_parse_protocol_stats() {
+        let mut counters = Counters::default();
+        counters.parse_table(SNMP, "/proc/net/snmp").unwrap();
+        counters.parse_table(NETSTAT, "/proc/net/netstat").unwrap();
+        counters.parse_flat(SNMP6).unwrap();
+        let stats = counters.into_stats().unwrap();
+
+        assert_eq!(stats.ip().in_receives(), 110_735);
+        assert_eq!(stats.ip().in_discards(), 3);
+        assert_eq!(stats.ip().frag_creates(), 11);
+        assert_eq!(stats.icmp().in_echo_reps(), 4);
+        assert_eq!(stats.icmp().out_echo_reps(), 6);
+        assert_eq!(stats.tcp().active_opens(), 133);
+        assert_eq!(stats.tcp().retrans_segs(), 6);
+        assert_eq!(stats.tcp().out_rsts(), 180);
+        assert_eq!(stats.tcp().listen_overflows(), Some(12));
+        assert_eq!(stats.tcp().listen_drops(), Some(15));
+        assert_eq!(stats.udp().no_ports(), 3);
+        assert_eq!(stats.udp().rcvbuf_errors(), 1);
+
+        assert_eq!(stats.ip6().unwrap().in_delivers(), 2);
+        assert_eq!(stats.icmp6().unwrap().out_echo_reps(), 2);
+        assert_eq!(stats.udp6().unwrap().in_datagrams(), 7);
+    }
+
+    #[test]
+    fn test_parse_without_ipv6() {
+        let mut counters = Counters::default();
+        counters.parse_table(SNMP, "/proc/net/snmp").unwrap();
+        counters.parse_table(NETSTAT, "/proc/net/netstat").unwrap();
+        let stats = counters.into_stats().unwrap();
+
+        assert!(stats.ip6().is_none());
+        assert!(stats.icmp6().is_none());
+        assert!(stats.udp6().is_none());
+    }
+
+    #[test]
+    fn test_parse_without_netstat() {
+        let mut counters = Counters::default();
+        counters.parse_table(SNMP, "/proc/net/snmp").unwrap();
+        let stats = counters.into_stats().unwrap();
+
+        assert_eq!(stats.tcp().active_opens(), 133);
+        assert!(stats.tcp().listen_overflows().is_none());
+        assert!(stats.tcp().listen_drops().is_none());
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        let mut counters = Counters::default();
+        assert!(counters
+            .parse_table("Ip: Forwarding\n", "/proc/net/snmp")
+            .is_err());
+        assert!(counters
+            .parse_table("Ip: Forwarding\nIcmp: 1\n", "/proc/net/snmp")
+            .is_err());
+    }
+}
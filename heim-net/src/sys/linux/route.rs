@@ -0,0 +1,159 @@
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use heim_common::prelude::*;
+use heim_common::utils::iter::*;
+use heim_runtime as rt;
+
+use crate::{Route, RouteFlags};
+
+fn parse_ipv4(value: &str) -> Result<Ipv4Addr> {
+    // Address is printed as a 32-bit word in the host byte order
+    let word = u32::from_str_radix(value, 16)?;
+
+    Ok(Ipv4Addr::from(word.to_ne_bytes()))
+}
+
+fn parse_ipv6(value: &str) -> Result<Ipv6Addr> {
+    Ok(Ipv6Addr::from(u128::from_str_radix(value, 16)?))
+}
+
+fn gateway(address: IpAddr) -> Option<IpAddr> {
+    if address.is_unspecified() {
+        None
+    } else {
+        Some(address)
+    }
+}
+
+/// Parses line from the `/proc/net/route` file.
+///
+/// See `fib_route_seq_show` function in the Linux sources for format details.
+fn parse_route(line: &str) -> Result<Route> {
+    let mut parts = line.split_whitespace();
+    let interface = parts.try_next()?.to_string();
+    let destination = parse_ipv4(parts.try_next()?)?;
+    let gateway_address = parse_ipv4(parts.try_next()?)?;
+    let flags = u32::from_str_radix(parts.try_next()?, 16)?;
+    let mut parts = parts.skip(2);
+    let metric = parts.try_parse_next()?;
+    let mask = parse_ipv4(parts.try_next()?)?;
+
+    Ok(Route {
+        destination: IpAddr::V4(destination),
+        prefix_len: u32::from(mask).count_ones() as u8,
+        gateway: gateway(IpAddr::V4(gateway_address)),
+        interface,
+        metric,
+        flags: RouteFlags::from_bits_truncate(flags),
+    })
+}
+
+/// Parses line from the `/proc/net/ipv6_route` file.
+///
+/// See `ipv6_route_native_seq_show` function in the Linux sources for format details.
+fn parse_ipv6_route(line: &str) -> Result<Route> {
+    let mut parts = line.split_whitespace();
+    let destination = parse_ipv6(parts.try_next()?)?;
+    let prefix_len = u8::from_str_radix(parts.try_next()?, 16)?;
+    // Skipping source address and its prefix length
+    let mut parts = parts.skip(2);
+    let gateway_address = parse_ipv6(parts.try_next()?)?;
+    let metric = u32::from_str_radix(parts.try_next()?, 16)?;
+    let mut parts = parts.skip(2);
+    let flags = u32::from_str_radix(parts.try_next()?, 16)?;
+    let interface = parts.try_next()?.to_string();
+
+    Ok(Route {
+        destination: IpAddr::V6(destination),
+        prefix_len,
+        gateway: gateway(IpAddr::V6(gateway_address)),
+        interface,
+        metric,
+        flags: RouteFlags::from_bits_truncate(flags),
+    })
+}
+
+fn ipv6_routes() -> impl Stream<Item = Result<Route>> {
+    let path = rt::fs::proc_path("net/ipv6_route");
+
+    rt::fs::read_to_string(path.clone())
+        .map(|result| match result {
+            Ok(contents) => Ok(contents),
+            // Missing if IPv6 is disabled
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+            Err(e) => Err(Error::from(e).with_file(path)),
+        })
+        .map_ok(|contents| {
+            let routes = contents.lines().map(parse_ipv6_route).collect::<Vec<_>>();
+
+            stream::iter(routes)
+        })
+        .try_flatten_stream()
+}
+
+pub fn routes() -> impl Stream<Item = Result<Route>> {
+    rt::fs::read_lines(rt::fs::proc_path("net/route"))
+        .try_flatten_stream()
+        .skip(1)
+        .map_err(Error::from)
+        .and_then(|line| future::ready(parse_route(&line)))
+        .chain(ipv6_routes())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use super::{parse_ipv6_route, parse_route};
+    use crate::RouteFlags;
+
+    #[test]
+    fn test_parse_route() {
+        let route =
+            parse_route("eth0\t00000000\t010200C0\t0003\t0\t0\t100\t00000000\t0\t0\t0").unwrap();
+        assert!(route.is_default());
+        assert_eq!(route.interface(), "eth0");
+        assert_eq!(
+            route.gateway(),
+            Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)))
+        );
+        assert_eq!(route.metric(), 100);
+        assert_eq!(route.flags(), RouteFlags::UP | RouteFlags::GATEWAY);
+        assert_eq!(route.mask(), IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+
+        let route =
+            parse_route("eth0\t000200C0\t00000000\t0001\t0\t0\t0\t00FFFFFF\t0\t0\t0").unwrap();
+        assert!(!route.is_default());
+        assert_eq!(route.destination(), IpAddr::V4(Ipv4Addr::new(192, 0, 2, 0)));
+        assert_eq!(route.prefix_len(), 24);
+        assert_eq!(route.mask(), IpAddr::V4(Ipv4Addr::new(255, 255, 255, 0)));
+        assert_eq!(route.gateway(), None);
+    }
+
+    #[test]
+    fn test_parse_ipv6_route() {
+        let line = "00000000000000000000000000000000 00 00000000000000000000000000000000 00 fd000000000000000000000000000001 00000400 00000001 00000000 00000003     eth0";
+        let route = parse_ipv6_route(line).unwrap();
+        assert!(route.is_default());
+        assert_eq!(
+            route.gateway(),
+            Some(IpAddr::V6(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1)))
+        );
+        assert_eq!(route.metric(), 1024);
+        assert_eq!(route.interface(), "eth0");
+
+        let line = "fe800000000000000000000000000000 40 00000000000000000000000000000000 00 00000000000000000000000000000000 00000100 00000002 00000000 00000001     eth0";
+        let route = parse_ipv6_route(line).unwrap();
+        assert_eq!(route.prefix_len(), 64);
+        assert_eq!(
+            route.mask(),
+            IpAddr::V6(Ipv6Addr::new(0xffff, 0xffff, 0xffff, 0xffff, 0, 0, 0, 0))
+        );
+        assert_eq!(route.gateway(), None);
+
+        let line = "00000000000000000000000000000000 00 00000000000000000000000000000000 00 00000000000000000000000000000000 ffffffff 00000001 00000000 00200200       lo";
+        let route = parse_ipv6_route(line).unwrap();
+        assert!(route.flags().contains(RouteFlags::REJECT));
+    }
+}
@@ -1,4 +1,10 @@
 mod bindings;
+mod connections;
 mod counters;
+mod nic;
+mod route;
 
+pub use self::connections::*;
 pub use self::counters::*;
+pub use self::nic::*;
+pub use self::route::*;
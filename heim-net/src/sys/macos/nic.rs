@@ -0,0 +1,19 @@
+use std::ffi::CString;
+
+use heim_common::prelude::*;
+
+use super::bindings::net_pf_route;
+
+pub async fn mtu(name: &str) -> Result<u32> {
+    let c_name = CString::new(name)?;
+    let index = unsafe { libc::if_nametoindex(c_name.as_ptr()) };
+    if index == 0 {
+        return Err(Error::last_os_error().with_ffi("if_nametoindex"));
+    }
+
+    let mut interfaces = unsafe { net_pf_route()? };
+    match interfaces.find(|msg| u32::from(msg.ifm_index) == index) {
+        Some(msg) => Ok(msg.ifm_data.ifi_mtu),
+        None => Err(Error::missing_key(name, "NET_RT_IFLIST2")),
+    }
+}
@@ -0,0 +1,87 @@
+use nix::errno::Errno;
+use nix::ifaddrs;
+use nix::net::if_::{if_nametoindex, InterfaceFlags as IfFlags};
+
+use heim_common::prelude::*;
+
+use super::to_address;
+use crate::{Interface, InterfaceAddress, InterfaceFlags};
+
+fn to_flags(flags: IfFlags) -> InterfaceFlags {
+    let mut result = InterfaceFlags::empty();
+    let pairs = [
+        (IfFlags::IFF_UP, InterfaceFlags::UP),
+        (IfFlags::IFF_BROADCAST, InterfaceFlags::BROADCAST),
+        (IfFlags::IFF_LOOPBACK, InterfaceFlags::LOOPBACK),
+        (IfFlags::IFF_POINTOPOINT, InterfaceFlags::POINT_TO_POINT),
+        (IfFlags::IFF_RUNNING, InterfaceFlags::RUNNING),
+        (IfFlags::IFF_MULTICAST, InterfaceFlags::MULTICAST),
+    ];
+    for (flag, value) in pairs.iter() {
+        if flags.contains(*flag) {
+            result.insert(*value);
+        }
+    }
+
+    result
+}
+
+fn group(addresses: Vec<ifaddrs::InterfaceAddress>) -> Result<Vec<Interface>> {
+    let mut interfaces: Vec<Interface> = Vec::new();
+    let mut vanished: Vec<String> = Vec::new();
+
+    for addr in addresses {
+        // Entries of the same interface are not necessarily adjacent,
+        // ex. Linux returns all `AF_PACKET` entries first, followed by `AF_INET`
+        // and `AF_INET6` ones, so the whole list is searched each time
+        let position = interfaces
+            .iter()
+            .position(|iface| iface.name == addr.interface_name);
+        let idx = match position {
+            Some(idx) => idx,
+            None if vanished.contains(&addr.interface_name) => continue,
+            None => {
+                let index = match if_nametoindex(addr.interface_name.as_str()) {
+                    Ok(index) => index,
+                    // Interface was removed after the `getifaddrs` call
+                    Err(nix::Error::Sys(Errno::ENODEV)) | Err(nix::Error::Sys(Errno::ENXIO)) => {
+                        vanished.push(addr.interface_name);
+                        continue;
+                    }
+                    Err(e) => return Err(Error::from(e).with_ffi("if_nametoindex")),
+                };
+                interfaces.push(Interface {
+                    name: addr.interface_name.clone(),
+                    index,
+                    flags: to_flags(addr.flags),
+                    addresses: Vec::new(),
+                });
+                interfaces.len() - 1
+            }
+        };
+
+        // Skipping unsupported address families
+        if let Some(address) = addr.address.as_ref().and_then(to_address) {
+            interfaces[idx].addresses.push(InterfaceAddress {
+                address,
+                netmask: addr.netmask.as_ref().and_then(to_address),
+                broadcast: addr.broadcast.as_ref().and_then(to_address),
+                destination: addr.destination.as_ref().and_then(to_address),
+            });
+        }
+    }
+
+    Ok(interfaces)
+}
+
+pub fn interfaces() -> impl Stream<Item = Result<Interface>> {
+    future::lazy(|_| {
+        // `nix::ifaddrs` structs are not safe to send between threads,
+        // so collecting them in a once
+        let addresses = ifaddrs::getifaddrs()?.collect::<Vec<_>>();
+        let interfaces = group(addresses)?;
+
+        Ok(stream::iter(interfaces).map(Ok))
+    })
+    .try_flatten_stream()
+}
@@ -1,3 +1,5 @@
+mod interface;
 mod nic;
 
+pub use self::interface::*;
 pub use self::nic::*;
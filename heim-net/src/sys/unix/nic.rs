@@ -1,3 +1,5 @@
+use std::net;
+
 use macaddr::MacAddr;
 use nix::ifaddrs;
 use nix::net::if_::InterfaceFlags;
@@ -54,6 +56,10 @@ impl Nic {
     pub fn is_multicast(&self) -> bool {
         self.0.flags.contains(InterfaceFlags::IFF_MULTICAST)
     }
+
+    pub async fn mtu(&self) -> Result<u32> {
+        crate::sys::mtu(self.name()).await
+    }
 }
 
 pub fn nic() -> impl Stream<Item = Result<Nic>> {
@@ -79,14 +85,27 @@ pub fn nic() -> impl Stream<Item = Result<Nic>> {
     })
 }
 
+/// Converts socket address into the `Address`.
+///
+/// `None` is returned for unsupported address families.
+pub fn to_address(s: &socket::SockAddr) -> Option<Address> {
+    use nix::sys::socket::SockAddr::*;
+
+    match *s {
+        Inet(addr) => match addr.to_std() {
+            addr @ net::SocketAddr::V4(..) => Some(Address::Inet(addr)),
+            addr @ net::SocketAddr::V6(..) => Some(Address::Inet6(addr)),
+        },
+        Link(addr) => Some(Address::Link(MacAddr::from(addr.addr()))),
+        _ => None,
+    }
+}
+
 impl From<&socket::SockAddr> for Address {
     fn from(s: &socket::SockAddr) -> Self {
-        use nix::sys::socket::SockAddr::*;
-
-        match *s {
-            Inet(addr) => Address::Inet(addr.to_std()),
-            Link(addr) => Address::Link(MacAddr::from(addr.addr())),
-            other => unimplemented!("Unknown sockaddr: {:?}", other),
+        match to_address(s) {
+            Some(address) => address,
+            None => unimplemented!("Unknown sockaddr: {:?}", s),
         }
     }
 }
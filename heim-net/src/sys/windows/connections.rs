@@ -0,0 +1,36 @@
+use std::net::SocketAddr;
+use std::path::Path;
+
+use heim_common::prelude::*;
+
+use crate::{ConnectionKind, ConnectionState, Protocol};
+
+#[derive(Debug)]
+pub struct Connection;
+
+impl Connection {
+    pub fn protocol(&self) -> Protocol {
+        unimplemented!()
+    }
+
+    pub fn local_address(&self) -> Option<SocketAddr> {
+        unimplemented!()
+    }
+
+    pub fn remote_address(&self) -> Option<SocketAddr> {
+        unimplemented!()
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        unimplemented!()
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        unimplemented!()
+    }
+}
+
+pub fn connections(_kind: ConnectionKind) -> impl Stream<Item = Result<Connection>> {
+    // TODO: Stub
+    stream::iter(vec![])
+}
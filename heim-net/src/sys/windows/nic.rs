@@ -45,9 +45,18 @@ impl Nic {
     pub fn is_multicast(&self) -> bool {
         unimplemented!()
     }
+
+    pub async fn mtu(&self) -> Result<u32> {
+        unimplemented!()
+    }
 }
 
 pub fn nic() -> impl Stream<Item = Result<Nic>> {
     // TODO: Stub
     stream::iter(vec![])
 }
+
+pub fn interfaces() -> impl Stream<Item = Result<crate::Interface>> {
+    // TODO: Stub
+    stream::iter(vec![])
+}
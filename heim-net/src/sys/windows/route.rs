@@ -0,0 +1,8 @@
+use heim_common::prelude::*;
+
+use crate::Route;
+
+pub fn routes() -> impl Stream<Item = Result<Route>> {
+    // TODO: Stub
+    stream::iter(vec![])
+}
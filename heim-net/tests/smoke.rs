@@ -26,6 +26,41 @@ async fn smoke_io_counters() {
 
         #[cfg(any(target_os = "linux", target_os = "windows"))]
         let _ = counter.drop_sent();
+
+        #[cfg(target_os = "linux")]
+        {
+            let _ = counter.fifo_recv();
+            let _ = counter.frame_recv();
+            let _ = counter.compressed_recv();
+            let _ = counter.multicast_recv();
+            let _ = counter.fifo_sent();
+            let _ = counter.collisions();
+            let _ = counter.carrier_sent();
+            let _ = counter.compressed_sent();
+
+            let statistics = counter.statistics().await.unwrap();
+            let _ = statistics.rx_crc_errors();
+            let _ = statistics.rx_missed_errors();
+            let _ = statistics.rx_nohandler();
+            let _ = statistics.tx_carrier_errors();
+            let _ = statistics.collisions();
+        }
+    }
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_io_counters_for_pid() {
+    let pid = std::process::id() as heim_common::Pid;
+    let counters = net::os::linux::io_counters_for_pid(pid);
+    rt::pin!(counters);
+    while let Some(counter) = counters.next().await {
+        let counter = counter.unwrap();
+
+        let _ = counter.interface();
+        let _ = counter.bytes_recv();
+        // Current process shares the network namespace with itself
+        assert!(counter.statistics().await.is_ok());
     }
 }
 
@@ -43,6 +78,7 @@ async fn smoke_nic() {
         let _ = iface.is_up();
         let _ = iface.is_loopback();
         let _ = iface.is_multicast();
+        assert!(iface.mtu().await.unwrap() > 0);
 
         #[cfg(target_os = "linux")]
         {
@@ -51,6 +87,12 @@ async fn smoke_nic() {
             let _ = iface.broadcast();
             let _ = iface.is_broadcast();
             let _ = iface.is_point_to_point();
+            let _ = iface.speed().await.unwrap();
+            let _ = iface.duplex().await.unwrap();
+            let _ = iface.operstate().await.unwrap();
+            let _ = iface.carrier().await.unwrap();
+            let _ = iface.tx_queue_len().await.unwrap();
+            let _ = iface.driver().await.unwrap();
         }
 
         #[cfg(target_os = "macos")]
@@ -63,3 +105,88 @@ async fn smoke_nic() {
         }
     }
 }
+
+#[heim_derive::test]
+async fn smoke_interfaces() {
+    let interfaces = net::interfaces();
+    rt::pin!(interfaces);
+    let mut names = Vec::new();
+    while let Some(iface) = interfaces.next().await {
+        let iface = iface.unwrap();
+
+        assert!(!names.contains(&iface.name().to_string()));
+        names.push(iface.name().to_string());
+
+        let _ = iface.index();
+        let _ = iface.flags();
+        for address in iface.addresses() {
+            let _ = address.address();
+            let _ = address.netmask();
+            let _ = address.broadcast();
+            let _ = address.destination();
+            let _ = address.prefix_len();
+        }
+    }
+}
+
+#[heim_derive::test]
+async fn smoke_routes() {
+    let routes = net::routes();
+    rt::pin!(routes);
+    while let Some(route) = routes.next().await {
+        let route = route.unwrap();
+
+        let _ = route.destination();
+        let _ = route.mask();
+        let _ = route.prefix_len();
+        let _ = route.gateway();
+        let _ = route.interface();
+        let _ = route.metric();
+        let _ = route.flags();
+        let _ = route.is_default();
+    }
+
+    if let Some(route) = net::default_gateway().await.unwrap() {
+        assert!(route.is_default());
+        assert!(route.gateway().is_some());
+    }
+}
+
+#[heim_derive::test]
+async fn smoke_connections() {
+    let connections = net::connections(net::ConnectionKind::All);
+    rt::pin!(connections);
+    while let Some(conn) = connections.next().await {
+        let conn = conn.unwrap();
+
+        let _ = conn.protocol();
+        let _ = conn.local_address();
+        let _ = conn.remote_address();
+        let _ = conn.path();
+        let _ = conn.state();
+
+        #[cfg(target_os = "linux")]
+        {
+            use heim_net::os::linux::ConnectionExt;
+
+            let _ = conn.inode();
+        }
+    }
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_protocol_stats() {
+    let stats = net::os::linux::protocol_stats().await.unwrap();
+
+    let _ = stats.ip().in_receives();
+    let _ = stats.ip().frag_fails();
+    let _ = stats.icmp().in_msgs();
+    let _ = stats.tcp().retrans_segs();
+    let _ = stats.tcp().listen_drops();
+    let _ = stats.udp().rcvbuf_errors();
+
+    if let Some(udp6) = stats.udp6() {
+        let _ = udp6.in_datagrams();
+    }
+}
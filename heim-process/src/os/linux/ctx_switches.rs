@@ -0,0 +1,24 @@
+/// Process context switches counters.
+///
+/// Returned by [ProcessExt::ctx_switches] method.
+///
+/// [ProcessExt::ctx_switches]: ./trait.ProcessExt.html#tymethod.ctx_switches
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CtxSwitches {
+    pub(crate) voluntary: u64,
+    pub(crate) involuntary: u64,
+}
+
+impl CtxSwitches {
+    /// Returns number of voluntary context switches,
+    /// ex. when process had blocked waiting for a resource.
+    pub fn voluntary(&self) -> u64 {
+        self.voluntary
+    }
+
+    /// Returns number of involuntary context switches,
+    /// ex. when process time slice had expired.
+    pub fn involuntary(&self) -> u64 {
+        self.involuntary
+    }
+}
@@ -1,3 +1,6 @@
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
 use heim_common::units::Information;
 
 /// Linux-specific extension to process [Memory] information.
@@ -29,3 +32,134 @@ impl MemoryExt for crate::Memory {
         self.as_ref().data()
     }
 }
+
+/// Detailed process memory information, based on the `/proc/<pid>/smaps` data.
+///
+/// Returned by [ProcessExt::full_memory] method.
+///
+/// [ProcessExt::full_memory]: ./trait.ProcessExt.html#tymethod.full_memory
+#[derive(Debug, Default, Clone)]
+pub struct FullMemory {
+    pub(crate) uss: Information,
+    pub(crate) pss: Information,
+    pub(crate) swap: Information,
+}
+
+impl FullMemory {
+    /// Returns USS (*unique set size*) - the amount of memory which is unique to a process
+    /// and which would be freed if the process was terminated right now.
+    pub fn uss(&self) -> Information {
+        self.uss
+    }
+
+    /// Returns PSS (*proportional set size*) - the amount of memory shared with other processes,
+    /// accounted in a way that the amount is divided evenly between the processes that share it.
+    pub fn pss(&self) -> Information {
+        self.pss
+    }
+
+    /// Returns the amount of memory that has been swapped out to disk.
+    pub fn swap(&self) -> Information {
+        self.swap
+    }
+}
+
+/// Memory region mapped by the process.
+///
+/// Returned by [ProcessExt::memory_maps] method,
+/// see `proc(5)` for the `/proc/<pid>/smaps` fields description.
+///
+/// [ProcessExt::memory_maps]: ./trait.ProcessExt.html#tymethod.memory_maps
+#[derive(Debug, Default, Clone)]
+pub struct MemoryMap {
+    pub(crate) address: Range<u64>,
+    pub(crate) permissions: String,
+    pub(crate) offset: u64,
+    pub(crate) path: Option<PathBuf>,
+    pub(crate) size: Information,
+    pub(crate) rss: Information,
+    pub(crate) pss: Information,
+    pub(crate) shared_clean: Information,
+    pub(crate) shared_dirty: Information,
+    pub(crate) private_clean: Information,
+    pub(crate) private_dirty: Information,
+    pub(crate) referenced: Information,
+    pub(crate) anonymous: Information,
+    pub(crate) swap: Information,
+}
+
+impl MemoryMap {
+    /// Returns address range of the mapped region.
+    pub fn address(&self) -> Range<u64> {
+        self.address.clone()
+    }
+
+    /// Returns region permissions in a `"rwxp"` form,
+    /// where the last symbol is `p` for private or `s` for shared mappings.
+    pub fn permissions(&self) -> &str {
+        &self.permissions
+    }
+
+    /// Returns offset into the mapped file.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns path to the mapped file.
+    ///
+    /// Pseudo-paths like `[heap]` or `[stack]` are returned as is,
+    /// `None` is returned for the anonymous mappings.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_ref().map(AsRef::as_ref)
+    }
+
+    /// Returns mapping size.
+    pub fn size(&self) -> Information {
+        self.size
+    }
+
+    /// Returns the amount of the mapping currently resident in RAM.
+    pub fn rss(&self) -> Information {
+        self.rss
+    }
+
+    /// Returns the process proportional share of this mapping.
+    pub fn pss(&self) -> Information {
+        self.pss
+    }
+
+    /// Returns the amount of clean shared pages in the mapping.
+    pub fn shared_clean(&self) -> Information {
+        self.shared_clean
+    }
+
+    /// Returns the amount of dirty shared pages in the mapping.
+    pub fn shared_dirty(&self) -> Information {
+        self.shared_dirty
+    }
+
+    /// Returns the amount of clean private pages in the mapping.
+    pub fn private_clean(&self) -> Information {
+        self.private_clean
+    }
+
+    /// Returns the amount of dirty private pages in the mapping.
+    pub fn private_dirty(&self) -> Information {
+        self.private_dirty
+    }
+
+    /// Returns the amount of memory currently marked as referenced or accessed.
+    pub fn referenced(&self) -> Information {
+        self.referenced
+    }
+
+    /// Returns the amount of memory that does not belong to any file.
+    pub fn anonymous(&self) -> Information {
+        self.anonymous
+    }
+
+    /// Returns the amount of would-be-anonymous memory which is swapped out.
+    pub fn swap(&self) -> Information {
+        self.swap
+    }
+}
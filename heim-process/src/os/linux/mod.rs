@@ -1,14 +1,27 @@
 //! Linux-specific extensions.
 
 use heim_common::prelude::BoxStream;
+use heim_common::units::Time;
 
 use crate::ProcessResult;
 
+mod ctx_switches;
 mod io_counters;
 mod memory;
+mod page_faults;
+mod pidfd;
+mod rlimit;
+mod sched;
 
+pub use self::ctx_switches::CtxSwitches;
 pub use self::io_counters::IoCounters;
-pub use self::memory::MemoryExt;
+pub use self::memory::{FullMemory, MemoryExt, MemoryMap};
+pub use self::page_faults::PageFaults;
+#[cfg(target_os = "linux")]
+pub(crate) use self::pidfd::pidfd_enabled;
+pub use self::pidfd::use_pidfd;
+pub use self::rlimit::{Resource, Rlimit};
+pub use self::sched::{IoPriority, SchedPolicy};
 
 /// Linux-specific extension to [Process]
 ///
@@ -21,6 +34,121 @@ pub trait ProcessExt {
     /// this method returns boxed `Future`. This behavior will change later.
     async fn io_counters(&self) -> ProcessResult<IoCounters>;
 
+    /// Returns future which resolves into detailed process memory information,
+    /// including USS, PSS and swap amounts.
+    ///
+    /// Data is loaded from the `/proc/<pid>/smaps_rollup` file if it is available (Linux 4.14+),
+    /// otherwise all the `/proc/<pid>/smaps` entries are summed.
+    /// Note that reading these files for other users processes requires elevated privileges.
+    async fn full_memory(&self) -> ProcessResult<FullMemory>;
+
+    /// Returns stream which yields memory regions mapped by this process.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Stream`. This behavior will change later.
+    fn memory_maps(&self) -> BoxStream<ProcessResult<MemoryMap>>;
+
+    /// Returns future which resolves into the process voluntary and involuntary
+    /// [context switches] counters.
+    ///
+    /// [context switches]: ./struct.CtxSwitches.html
+    async fn ctx_switches(&self) -> ProcessResult<CtxSwitches>;
+
+    /// Returns future which resolves into the process minor and major [page faults] counters.
+    ///
+    /// [page faults]: ./struct.PageFaults.html
+    async fn page_faults(&self) -> ProcessResult<PageFaults>;
+
+    /// Returns future which resolves into the process priority, as it is seen by the kernel.
+    ///
+    /// For the time-sharing scheduling policies it is the nice value increased by `20`,
+    /// for the real-time ones it is the negated real-time priority decreased by `1`.
+    async fn priority(&self) -> ProcessResult<i32>;
+
+    /// Returns future which resolves into the process real-time priority,
+    /// which is in the `1..=99` range for the real-time scheduling policies and `0` otherwise.
+    ///
+    /// See also [sched_policy](#tymethod.sched_policy) method.
+    async fn rt_priority(&self) -> ProcessResult<u32>;
+
+    /// Returns future which resolves into the number of CPU the process was last executed on.
+    async fn processor(&self) -> ProcessResult<usize>;
+
+    /// Returns future which resolves into the aggregated block I/O delays of the process,
+    /// i.e. time spent waiting for the block I/O completion.
+    ///
+    /// Delays are accounted only if the kernel was built with the `CONFIG_TASK_DELAY_ACCT` option;
+    /// since Linux 5.14 accounting should be also enabled with the `delayacct` boot option
+    /// or the `kernel.task_delayacct` sysctl, otherwise zero time is returned.
+    async fn blkio_delay(&self) -> ProcessResult<Time>;
+
+    /// Returns future which resolves into the list of CPUs this process is allowed to run on.
+    ///
+    /// CPUs are identified by their indexes, same as in the `sched_getaffinity(2)`.
+    async fn cpu_affinity(&self) -> ProcessResult<Vec<usize>>;
+
+    /// Restricts this process to run on the `cpus` given only.
+    ///
+    /// Changing the affinity of the other users' processes requires
+    /// `CAP_SYS_NICE` capability, otherwise `ProcessError::AccessDenied` error is returned.
+    async fn set_cpu_affinity(&self, cpus: &[usize]) -> ProcessResult<()>;
+
+    /// Returns future which resolves into the process [scheduling policy].
+    ///
+    /// [scheduling policy]: ./enum.SchedPolicy.html
+    async fn sched_policy(&self) -> ProcessResult<SchedPolicy>;
+
+    /// Returns future which resolves into the process static scheduling priority.
+    ///
+    /// Priority is in the `1..=99` range for the real-time policies
+    /// and is always `0` for the other ones.
+    async fn sched_priority(&self) -> ProcessResult<i32>;
+
+    /// Changes the process scheduling policy and its static priority.
+    ///
+    /// Setting the real-time policies usually requires `CAP_SYS_NICE` capability,
+    /// otherwise `ProcessError::AccessDenied` error is returned.
+    ///
+    /// [`SchedPolicy::Deadline`] policy can't be set with this method
+    /// and an error with `io::ErrorKind::InvalidInput` kind is returned for it.
+    ///
+    /// [`SchedPolicy::Deadline`]: ./enum.SchedPolicy.html#variant.Deadline
+    async fn set_sched_policy(&self, policy: SchedPolicy, priority: i32) -> ProcessResult<()>;
+
+    /// Returns future which resolves into the process [I/O priority].
+    ///
+    /// [I/O priority]: ./enum.IoPriority.html
+    async fn io_priority(&self) -> ProcessResult<IoPriority>;
+
+    /// Changes the process [I/O priority].
+    ///
+    /// Setting the real-time class or raising the priority level
+    /// of the other users' processes requires elevated privileges,
+    /// otherwise `ProcessError::AccessDenied` error is returned.
+    ///
+    /// [I/O priority]: ./enum.IoPriority.html
+    async fn set_io_priority(&self, priority: IoPriority) -> ProcessResult<()>;
+
+    /// Returns future which resolves into the process soft and hard [limits] for `resource`.
+    ///
+    /// Limits are fetched with `prlimit(2)`, if it is not permitted,
+    /// they are read from the `/proc/<pid>/limits` file instead.
+    ///
+    /// [limits]: ./struct.Rlimit.html
+    async fn rlimit(&self, resource: Resource) -> ProcessResult<Rlimit>;
+
+    /// Changes the process soft and hard limits for `resource`, `None` stands for "unlimited".
+    ///
+    /// Raising the hard limit or changing the limits of the other users' processes
+    /// requires `CAP_SYS_RESOURCE` capability,
+    /// otherwise `ProcessError::AccessDenied` error is returned.
+    async fn set_rlimit(
+        &self,
+        resource: Resource,
+        soft: Option<u64>,
+        hard: Option<u64>,
+    ) -> ProcessResult<()>;
+
     /// Returns stream which yield this process [IO counters] for each network interface.
     ///
     /// Since `-> impl Trait` is not allowed yet in the trait methods,
@@ -29,6 +157,19 @@ pub trait ProcessExt {
     /// [IO counters]: ./struct.IoCounters.html
     #[cfg(target_os = "linux")] // TODO: will be undocumented for other platforms
     fn net_io_counters(&self) -> BoxStream<ProcessResult<heim_net::IoCounters>>;
+
+    /// Returns stream which yields network [connections] opened by this process.
+    ///
+    /// Sockets are matched with the system-wide connections list by their inodes,
+    /// therefore reading the `/proc/<pid>/fd` directory of the other users' processes
+    /// will fail with the `ProcessError::AccessDenied` error.
+    ///
+    /// [connections]: ../../../heim_net/struct.Connection.html
+    #[cfg(target_os = "linux")] // TODO: will be undocumented for other platforms
+    fn connections(
+        &self,
+        kind: heim_net::ConnectionKind,
+    ) -> BoxStream<ProcessResult<heim_net::Connection>>;
 }
 
 #[cfg(target_os = "linux")]
@@ -38,7 +179,89 @@ impl ProcessExt for crate::Process {
         self.as_ref().io_counters().await
     }
 
+    async fn full_memory(&self) -> ProcessResult<FullMemory> {
+        self.as_ref().full_memory().await
+    }
+
+    fn memory_maps(&self) -> BoxStream<ProcessResult<MemoryMap>> {
+        self.as_ref().memory_maps()
+    }
+
+    async fn ctx_switches(&self) -> ProcessResult<CtxSwitches> {
+        self.as_ref().ctx_switches().await
+    }
+
+    async fn page_faults(&self) -> ProcessResult<PageFaults> {
+        self.as_ref().page_faults().await
+    }
+
+    async fn priority(&self) -> ProcessResult<i32> {
+        self.as_ref().priority().await
+    }
+
+    async fn rt_priority(&self) -> ProcessResult<u32> {
+        self.as_ref().rt_priority().await
+    }
+
+    async fn processor(&self) -> ProcessResult<usize> {
+        self.as_ref().processor().await
+    }
+
+    async fn blkio_delay(&self) -> ProcessResult<Time> {
+        self.as_ref().blkio_delay().await
+    }
+
+    async fn cpu_affinity(&self) -> ProcessResult<Vec<usize>> {
+        self.as_ref().cpu_affinity().await
+    }
+
+    async fn set_cpu_affinity(&self, cpus: &[usize]) -> ProcessResult<()> {
+        self.as_ref().set_cpu_affinity(cpus).await
+    }
+
+    async fn sched_policy(&self) -> ProcessResult<SchedPolicy> {
+        self.as_ref().sched_policy().await
+    }
+
+    async fn sched_priority(&self) -> ProcessResult<i32> {
+        self.as_ref().sched_priority().await
+    }
+
+    async fn set_sched_policy(&self, policy: SchedPolicy, priority: i32) -> ProcessResult<()> {
+        self.as_ref().set_sched_policy(policy, priority).await
+    }
+
+    async fn io_priority(&self) -> ProcessResult<IoPriority> {
+        self.as_ref().io_priority().await
+    }
+
+    async fn set_io_priority(&self, priority: IoPriority) -> ProcessResult<()> {
+        self.as_ref().set_io_priority(priority).await
+    }
+
+    async fn rlimit(&self, resource: Resource) -> ProcessResult<Rlimit> {
+        self.as_ref().rlimit(resource).await
+    }
+
+    async fn set_rlimit(
+        &self,
+        resource: Resource,
+        soft: Option<u64>,
+        hard: Option<u64>,
+    ) -> ProcessResult<()> {
+        self.as_ref()
+            .set_rlimit(resource, Rlimit { soft, hard })
+            .await
+    }
+
     fn net_io_counters(&self) -> BoxStream<ProcessResult<heim_net::IoCounters>> {
         self.as_ref().net_io_counters()
     }
+
+    fn connections(
+        &self,
+        kind: heim_net::ConnectionKind,
+    ) -> BoxStream<ProcessResult<heim_net::Connection>> {
+        self.as_ref().connections(kind)
+    }
 }
@@ -0,0 +1,22 @@
+/// Process page faults counters.
+///
+/// Returned by [ProcessExt::page_faults] method.
+///
+/// [ProcessExt::page_faults]: ./trait.ProcessExt.html#tymethod.page_faults
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PageFaults {
+    pub(crate) minor: u64,
+    pub(crate) major: u64,
+}
+
+impl PageFaults {
+    /// Returns number of minor faults, which have not required loading a memory page from disk.
+    pub fn minor(&self) -> u64 {
+        self.minor
+    }
+
+    /// Returns number of major faults, which have required loading a memory page from disk.
+    pub fn major(&self) -> u64 {
+        self.major
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static PIDFD_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables usage of the process file descriptors (pidfd).
+///
+/// When enabled, each [Process] loaded after this call opens a pidfd,
+/// which is later used to send signals to the process, check if it is still running
+/// and wait for its exit. Contrary to the PID-based approach, pidfd always refers
+/// to the same process, so there is no way to accidentally signal some other process,
+/// which had re-used the PID in between.
+///
+/// pidfd is available since Linux 5.3, for older kernels this setting has no effect.
+/// Note that each [Process] instance holds an opened file descriptor while this mode is enabled,
+/// which counts against the `RLIMIT_NOFILE` limit.
+///
+/// Disabled by default.
+///
+/// [Process]: ../../struct.Process.html
+pub fn use_pidfd(enabled: bool) {
+    PIDFD_ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn pidfd_enabled() -> bool {
+    PIDFD_ENABLED.load(Ordering::SeqCst)
+}
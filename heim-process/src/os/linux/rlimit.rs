@@ -0,0 +1,88 @@
+/// Process resources, which consumption can be limited.
+///
+/// See `getrlimit(2)` for details.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Resource {
+    /// Maximum size of the process virtual memory (`RLIMIT_AS`), in bytes.
+    AddressSpace,
+    /// Maximum size of a core file (`RLIMIT_CORE`), in bytes.
+    Core,
+    /// Amount of CPU time process can consume (`RLIMIT_CPU`), in seconds.
+    Cpu,
+    /// Maximum size of the process data segment (`RLIMIT_DATA`), in bytes.
+    Data,
+    /// Maximum size of files process may create (`RLIMIT_FSIZE`), in bytes.
+    FileSize,
+    /// Maximum number of file locks (`RLIMIT_LOCKS`).
+    Locks,
+    /// Maximum amount of memory that may be locked into RAM (`RLIMIT_MEMLOCK`), in bytes.
+    MemLock,
+    /// Maximum amount of memory for POSIX message queues (`RLIMIT_MSGQUEUE`), in bytes.
+    MsgQueue,
+    /// Ceiling to which the process nice value can be raised (`RLIMIT_NICE`).
+    Nice,
+    /// Maximum number of file descriptors opened (`RLIMIT_NOFILE`).
+    NoFile,
+    /// Maximum number of processes for the process real user ID (`RLIMIT_NPROC`).
+    NProc,
+    /// Maximum size of the process resident set (`RLIMIT_RSS`), in bytes.
+    Rss,
+    /// Ceiling on the real-time priority (`RLIMIT_RTPRIO`).
+    RtPrio,
+    /// Amount of CPU time process under real-time scheduling can consume
+    /// without making a blocking system call (`RLIMIT_RTTIME`), in microseconds.
+    RtTime,
+    /// Maximum number of signals queued for the process real user ID (`RLIMIT_SIGPENDING`).
+    SigPending,
+    /// Maximum size of the process stack (`RLIMIT_STACK`), in bytes.
+    Stack,
+}
+
+impl Resource {
+    /// Returns resource name as it is shown in the `/proc/<pid>/limits` file.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn proc_name(self) -> &'static str {
+        match self {
+            Resource::AddressSpace => "Max address space",
+            Resource::Core => "Max core file size",
+            Resource::Cpu => "Max cpu time",
+            Resource::Data => "Max data size",
+            Resource::FileSize => "Max file size",
+            Resource::Locks => "Max file locks",
+            Resource::MemLock => "Max locked memory",
+            Resource::MsgQueue => "Max msgqueue size",
+            Resource::Nice => "Max nice priority",
+            Resource::NoFile => "Max open files",
+            Resource::NProc => "Max processes",
+            Resource::Rss => "Max resident set",
+            Resource::RtPrio => "Max realtime priority",
+            Resource::RtTime => "Max realtime timeout",
+            Resource::SigPending => "Max pending signals",
+            Resource::Stack => "Max stack size",
+        }
+    }
+}
+
+/// Soft and hard limits for the process [Resource].
+///
+/// Returned by [ProcessExt::rlimit] method, `None` values are standing for "unlimited".
+///
+/// [Resource]: ./enum.Resource.html
+/// [ProcessExt::rlimit]: ./trait.ProcessExt.html#tymethod.rlimit
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rlimit {
+    pub(crate) soft: Option<u64>,
+    pub(crate) hard: Option<u64>,
+}
+
+impl Rlimit {
+    /// Returns soft limit, which is enforced by the kernel.
+    pub fn soft(&self) -> Option<u64> {
+        self.soft
+    }
+
+    /// Returns hard limit, which acts as a ceiling for the soft limit.
+    pub fn hard(&self) -> Option<u64> {
+        self.hard
+    }
+}
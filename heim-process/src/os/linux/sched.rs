@@ -0,0 +1,77 @@
+use std::convert::TryFrom;
+use std::io;
+
+/// Linux scheduling policies.
+///
+/// See `sched(7)` for details.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SchedPolicy {
+    /// Default time-sharing scheduling (`SCHED_OTHER`).
+    Other,
+    /// First-in, first-out real-time scheduling (`SCHED_FIFO`).
+    Fifo,
+    /// Round-robin real-time scheduling (`SCHED_RR`).
+    RoundRobin,
+    /// Time-sharing scheduling for the CPU-intensive batch processes (`SCHED_BATCH`).
+    Batch,
+    /// Scheduling for the very low priority background jobs (`SCHED_IDLE`).
+    Idle,
+    /// Deadline scheduling (`SCHED_DEADLINE`).
+    ///
+    /// This policy can be only read, as it requires additional parameters to be set.
+    Deadline,
+}
+
+// Declared at `include/uapi/linux/sched.h`, missing in the `libc` crate
+#[cfg(target_os = "linux")]
+const SCHED_DEADLINE: libc::c_int = 6;
+#[cfg(target_os = "linux")]
+const SCHED_RESET_ON_FORK: libc::c_int = 0x4000_0000;
+
+#[cfg(target_os = "linux")]
+impl TryFrom<libc::c_int> for SchedPolicy {
+    type Error = io::Error;
+
+    fn try_from(value: libc::c_int) -> io::Result<Self> {
+        // Flag might be OR-ed into the policy value
+        match value & !SCHED_RESET_ON_FORK {
+            libc::SCHED_OTHER => Ok(SchedPolicy::Other),
+            libc::SCHED_FIFO => Ok(SchedPolicy::Fifo),
+            libc::SCHED_RR => Ok(SchedPolicy::RoundRobin),
+            libc::SCHED_BATCH => Ok(SchedPolicy::Batch),
+            libc::SCHED_IDLE => Ok(SchedPolicy::Idle),
+            SCHED_DEADLINE => Ok(SchedPolicy::Deadline),
+            _ => Err(io::Error::from(io::ErrorKind::InvalidData)),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl From<SchedPolicy> for libc::c_int {
+    fn from(policy: SchedPolicy) -> Self {
+        match policy {
+            SchedPolicy::Other => libc::SCHED_OTHER,
+            SchedPolicy::Fifo => libc::SCHED_FIFO,
+            SchedPolicy::RoundRobin => libc::SCHED_RR,
+            SchedPolicy::Batch => libc::SCHED_BATCH,
+            SchedPolicy::Idle => libc::SCHED_IDLE,
+            SchedPolicy::Deadline => SCHED_DEADLINE,
+        }
+    }
+}
+
+/// Process I/O scheduling class and priority level.
+///
+/// Levels are in the `0..=7` range, where lower value means higher priority.
+/// See `ioprio_set(2)` for details.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IoPriority {
+    /// No I/O priority was set, it is derived from the process nice value.
+    None,
+    /// Real-time class, process gets the first access to the disk.
+    RealTime(u8),
+    /// Best-effort class, default for the processes without I/O priority set.
+    BestEffort(u8),
+    /// Idle class, process gets the disk access only when no one else needs it.
+    Idle,
+}
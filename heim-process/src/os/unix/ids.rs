@@ -0,0 +1,57 @@
+/// Real, effective and saved user IDs of the process.
+///
+/// Returned by [ProcessExt::uids] method.
+///
+/// [ProcessExt::uids]: ./trait.ProcessExt.html#tymethod.uids
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Uids {
+    pub(crate) real: u32,
+    pub(crate) effective: u32,
+    pub(crate) saved: u32,
+}
+
+impl Uids {
+    /// Returns real user ID.
+    pub fn real(&self) -> u32 {
+        self.real
+    }
+
+    /// Returns effective user ID.
+    pub fn effective(&self) -> u32 {
+        self.effective
+    }
+
+    /// Returns saved set-user-ID.
+    pub fn saved(&self) -> u32 {
+        self.saved
+    }
+}
+
+/// Real, effective and saved group IDs of the process.
+///
+/// Returned by [ProcessExt::gids] method.
+///
+/// [ProcessExt::gids]: ./trait.ProcessExt.html#tymethod.gids
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Gids {
+    pub(crate) real: u32,
+    pub(crate) effective: u32,
+    pub(crate) saved: u32,
+}
+
+impl Gids {
+    /// Returns real group ID.
+    pub fn real(&self) -> u32 {
+        self.real
+    }
+
+    /// Returns effective group ID.
+    pub fn effective(&self) -> u32 {
+        self.effective
+    }
+
+    /// Returns saved set-group-ID.
+    pub fn saved(&self) -> u32 {
+        self.saved
+    }
+}
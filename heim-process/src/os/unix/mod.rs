@@ -1,11 +1,17 @@
 //! Unix-specific extensions.
 
+use std::path::PathBuf;
+
 use heim_common::prelude::BoxFuture;
+#[cfg(unix)]
+use heim_common::prelude::FutureExt;
 
-use crate::ProcessResult;
+use crate::{ExitStatus, Pid, ProcessResult};
 
+mod ids;
 mod signal;
 
+pub use self::ids::{Gids, Uids};
 pub use self::signal::Signal;
 
 /// Unix-specific extension to [Process].
@@ -17,6 +23,80 @@ pub trait ProcessExt {
     /// Since `-> impl Trait` is not allowed yet in the trait methods,
     /// this method returns boxed `Future`. This behavior will change later.
     fn signal(&self, signal: Signal) -> BoxFuture<ProcessResult<()>>;
+
+    /// Returns real, effective and saved user IDs of the process.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn uids(&self) -> BoxFuture<ProcessResult<Uids>>;
+
+    /// Returns real, effective and saved group IDs of the process.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn gids(&self) -> BoxFuture<ProcessResult<Gids>>;
+
+    /// Returns supplementary group IDs of the process.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn groups(&self) -> BoxFuture<ProcessResult<Vec<u32>>>;
+
+    /// Returns the process nice value.
+    ///
+    /// Nice value is in the `-20..=19` range, where lower value means higher priority.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn nice(&self) -> BoxFuture<ProcessResult<i32>>;
+
+    /// Changes the process nice value.
+    ///
+    /// Same as [signal](#tymethod.signal), this method checks first if process is still running,
+    /// so the process which PID was re-used will not be affected.
+    /// Lowering the nice value usually requires elevated privileges,
+    /// otherwise `ProcessError::AccessDenied` error is returned.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn set_nice(&self, value: i32) -> BoxFuture<ProcessResult<()>>;
+
+    /// Returns path to the controlling terminal of the process,
+    /// or `None` if process does not have one.
+    ///
+    /// Terminal device is resolved by looking through the `/dev/tty*` and `/dev/pts/*` devices.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn terminal(&self) -> BoxFuture<ProcessResult<Option<PathBuf>>>;
+
+    /// Returns ID of the process group this process belongs to.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn pgid(&self) -> BoxFuture<ProcessResult<Pid>>;
+
+    /// Returns ID of the session this process belongs to.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn sid(&self) -> BoxFuture<ProcessResult<Pid>>;
+
+    /// Returns ID of the foreground process group of the process controlling terminal,
+    /// or `None` if process does not have one.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn tpgid(&self) -> BoxFuture<ProcessResult<Option<Pid>>>;
+
+    /// Sends the signal to all members of the process group this process belongs to.
+    ///
+    /// Same as [signal](#tymethod.signal), this method checks first if process is still running,
+    /// so the process group of the process which PID was re-used will not be affected.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Future`. This behavior will change later.
+    fn signal_group(&self, signal: Signal) -> BoxFuture<ProcessResult<()>>;
 }
 
 #[cfg(unix)]
@@ -24,4 +104,59 @@ impl ProcessExt for crate::Process {
     fn signal(&self, signal: Signal) -> BoxFuture<ProcessResult<()>> {
         self.as_ref().signal(signal)
     }
+
+    fn uids(&self) -> BoxFuture<ProcessResult<Uids>> {
+        self.as_ref().uids().boxed()
+    }
+
+    fn gids(&self) -> BoxFuture<ProcessResult<Gids>> {
+        self.as_ref().gids().boxed()
+    }
+
+    fn groups(&self) -> BoxFuture<ProcessResult<Vec<u32>>> {
+        self.as_ref().groups().boxed()
+    }
+
+    fn nice(&self) -> BoxFuture<ProcessResult<i32>> {
+        self.as_ref().nice().boxed()
+    }
+
+    fn set_nice(&self, value: i32) -> BoxFuture<ProcessResult<()>> {
+        self.as_ref().set_nice(value).boxed()
+    }
+
+    fn terminal(&self) -> BoxFuture<ProcessResult<Option<PathBuf>>> {
+        self.as_ref().terminal().boxed()
+    }
+
+    fn pgid(&self) -> BoxFuture<ProcessResult<Pid>> {
+        self.as_ref().pgid().boxed()
+    }
+
+    fn sid(&self) -> BoxFuture<ProcessResult<Pid>> {
+        self.as_ref().sid().boxed()
+    }
+
+    fn tpgid(&self) -> BoxFuture<ProcessResult<Option<Pid>>> {
+        self.as_ref().tpgid().boxed()
+    }
+
+    fn signal_group(&self, signal: Signal) -> BoxFuture<ProcessResult<()>> {
+        self.as_ref().signal_group(signal).boxed()
+    }
+}
+
+/// Unix-specific extension to [ExitStatus].
+///
+/// [ExitStatus]: ../../struct.ExitStatus.html
+pub trait ExitStatusExt {
+    /// Returns the signal which terminated the process, if any.
+    fn signal(&self) -> Option<Signal>;
+}
+
+#[cfg(unix)]
+impl ExitStatusExt for ExitStatus {
+    fn signal(&self) -> Option<Signal> {
+        self.signal
+    }
 }
@@ -1,9 +1,12 @@
+use std::collections::HashMap;
 use std::ops;
 use std::time::Instant;
 
 use heim_common::units::{ratio, time, Ratio};
 
-use super::CpuTime;
+use super::{CpuTime, Process};
+use crate::sys::UniqueId;
+use crate::{ProcessError, ProcessResult};
 
 /// Process CPU usage measurement.
 ///
@@ -18,8 +21,16 @@ pub struct CpuUsage {
 impl ops::Sub<CpuUsage> for CpuUsage {
     type Output = Ratio;
 
-    #[allow(clippy::suspicious_arithmetic_impl, clippy::cast_lossless)]
     fn sub(self, rhs: CpuUsage) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<'a> ops::Sub<&'a CpuUsage> for &'a CpuUsage {
+    type Output = Ratio;
+
+    #[allow(clippy::suspicious_arithmetic_impl, clippy::cast_lossless)]
+    fn sub(self, rhs: &CpuUsage) -> Self::Output {
         let delta_proc = (self.cpu_time.user() - rhs.cpu_time.user())
             + (self.cpu_time.system() - rhs.cpu_time.system());
         let delta_time = self.at - rhs.at;
@@ -27,8 +38,8 @@ impl ops::Sub<CpuUsage> for CpuUsage {
         // TODO: Can be replaced with a `delta_time.as_secs_f64()`
         // as soon as https://github.com/rust-lang/rust/issues/54361 will be stable
         const NANOS_PER_SEC: u32 = 1_000_000_000;
-        let mut delta_time_secs =
-            (delta_time.as_secs() as f64) + (delta_time.as_nanos() as f64) / (NANOS_PER_SEC as f64);
+        let mut delta_time_secs = (delta_time.as_secs() as f64)
+            + f64::from(delta_time.subsec_nanos()) / f64::from(NANOS_PER_SEC);
 
         // Time should calculated across all the cores available
         delta_time_secs *= self.cpu_count as f64;
@@ -43,3 +54,103 @@ impl ops::Sub<CpuUsage> for CpuUsage {
         }
     }
 }
+
+#[derive(Debug)]
+struct Sample {
+    usage: CpuUsage,
+    measured: bool,
+}
+
+/// Tracker for the CPU usage of multiple processes.
+///
+/// It remembers the previous [CPU usage measurement] for each process,
+/// so each consequent call returns the CPU usage for the period elapsed since the previous call
+/// for the same process.
+/// Processes are distinguished not by their pids only, so pid reuse is handled too.
+///
+/// ## Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use heim_common::prelude::*;
+/// # use heim_common::units::ratio;
+/// # use heim_process::{self as process, CpuUsageTracker, ProcessResult};
+/// #
+/// # #[heim_derive::main]
+/// # async fn main() -> ProcessResult<()> {
+/// let mut tracker = CpuUsageTracker::new();
+/// let process = process::current().await?;
+/// let _ = tracker.cpu_usage(&process).await?;
+/// // Or any other async timer at your choice
+/// futures_timer::Delay::new(Duration::from_millis(100)).await;
+/// if let Some(usage) = tracker.cpu_usage(&process).await? {
+///     println!("CPU usage: {} %", usage.get::<ratio::percent>());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [CPU usage measurement]: ./struct.CpuUsage.html
+#[derive(Debug, Default)]
+pub struct CpuUsageTracker {
+    samples: HashMap<UniqueId, Sample>,
+}
+
+impl CpuUsageTracker {
+    /// Creates new tracker.
+    pub fn new() -> CpuUsageTracker {
+        CpuUsageTracker::default()
+    }
+
+    /// Returns CPU usage of the `process` since the previous call for the same process.
+    ///
+    /// `None` is returned when `process` is measured for the first time.
+    /// See [Process::cpu_usage] method for the result interpretation.
+    ///
+    /// [Process::cpu_usage]: ./struct.Process.html#method.cpu_usage
+    pub async fn cpu_usage(&mut self, process: &Process) -> ProcessResult<Option<Ratio>> {
+        let id = process.as_ref().unique_id();
+        let usage = match process.cpu_usage().await {
+            Ok(usage) => usage,
+            Err(e @ ProcessError::NoSuchProcess(..)) => {
+                let _ = self.samples.remove(id);
+                return Err(e);
+            }
+            Err(e) => return Err(e),
+        };
+
+        let usage_ratio = self
+            .samples
+            .get(id)
+            .map(|previous| &usage - &previous.usage);
+        let sample = Sample {
+            usage,
+            measured: true,
+        };
+        let _ = self.samples.insert(id.clone(), sample);
+
+        Ok(usage_ratio)
+    }
+
+    /// Forgets the processes which were not measured since the previous `prune` call.
+    ///
+    /// Should be called periodically, ex. after each round of measurements,
+    /// in order to release the memory used by the exited processes.
+    pub fn prune(&mut self) {
+        self.samples.retain(|_, sample| {
+            let measured = sample.measured;
+            sample.measured = false;
+            measured
+        });
+    }
+
+    /// Returns the number of processes tracked.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns `true` if no processes are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
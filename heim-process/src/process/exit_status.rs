@@ -0,0 +1,29 @@
+#[cfg(unix)]
+use crate::os::unix::Signal;
+
+/// Process exit status.
+///
+/// Returned by [Process::wait] method.
+///
+/// Exit status can be obtained only for the children of the current process,
+/// for other processes it is known only that they are not running anymore.
+///
+/// [Process::wait]: ./struct.Process.html#method.wait
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct ExitStatus {
+    pub(crate) code: Option<i32>,
+    #[cfg(unix)]
+    pub(crate) signal: Option<Signal>,
+}
+
+impl ExitStatus {
+    /// Returns the process exit code, if it is known.
+    ///
+    /// For *nix systems `None` is also returned if the process was terminated by a signal,
+    /// see [`os::unix::ExitStatusExt`] for details.
+    ///
+    /// [`os::unix::ExitStatusExt`]: ./os/unix/trait.ExitStatusExt.html
+    pub fn code(&self) -> Option<i32> {
+        self.code
+    }
+}
@@ -0,0 +1,245 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use heim_common::prelude::*;
+
+use super::{processes, Attribute, Process, ProcessInfo, Status};
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Processes lookup builder.
+///
+/// Created by the [find] function, see its documentation for details.
+///
+/// [find]: ./fn.find.html
+#[derive(Debug, Default, Clone)]
+pub struct Finder {
+    name: Option<String>,
+    #[cfg(feature = "regex")]
+    name_regex: Option<regex::Regex>,
+    command: Option<String>,
+    exe: Option<PathBuf>,
+    username: Option<String>,
+    parent_pid: Option<Pid>,
+    status: Option<Status>,
+}
+
+/// Checks the loaded attribute value with the `predicate`.
+///
+/// Processes, which attribute is not available, are not matching.
+fn check<T, F>(value: Option<&ProcessResult<T>>, predicate: F) -> bool
+where
+    F: FnOnce(&T) -> bool,
+{
+    match value {
+        Some(Ok(value)) => predicate(value),
+        _ => false,
+    }
+}
+
+impl Finder {
+    /// Matches processes with the exact `name`.
+    pub fn name<T: Into<String>>(mut self, name: T) -> Finder {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Matches processes which name matches the `regex`.
+    #[cfg(feature = "regex")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+    pub fn name_regex(mut self, regex: regex::Regex) -> Finder {
+        self.name_regex = Some(regex);
+        self
+    }
+
+    /// Matches processes which command line contains the `substring`.
+    ///
+    /// Command line arguments are joined with spaces before the search.
+    pub fn command<T: Into<String>>(mut self, substring: T) -> Finder {
+        self.command = Some(substring.into());
+        self
+    }
+
+    /// Matches processes which executable is located at the `path`.
+    pub fn exe<T: Into<PathBuf>>(mut self, path: T) -> Finder {
+        self.exe = Some(path.into());
+        self
+    }
+
+    /// Matches processes owned by the user with `username`.
+    ///
+    /// ## Compatibility
+    ///
+    /// Implemented only for Linux and macOS for now,
+    /// for Windows no processes are matching this condition.
+    pub fn username<T: Into<String>>(mut self, username: T) -> Finder {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Matches children of the process with `pid`.
+    pub fn parent_pid(mut self, pid: Pid) -> Finder {
+        self.parent_pid = Some(pid);
+        self
+    }
+
+    /// Matches processes with `status`.
+    pub fn status(mut self, status: Status) -> Finder {
+        self.status = Some(status);
+        self
+    }
+
+    fn has_name_filter(&self) -> bool {
+        #[cfg(feature = "regex")]
+        {
+            if self.name_regex.is_some() {
+                return true;
+            }
+        }
+
+        self.name.is_some()
+    }
+
+    fn matches_name(&self, info: &ProcessInfo) -> bool {
+        if let Some(name) = &self.name {
+            if !check(info.name(), |value| value == name) {
+                return false;
+            }
+        }
+
+        #[cfg(feature = "regex")]
+        {
+            if let Some(regex) = &self.name_regex {
+                if !check(info.name(), |value| regex.is_match(value)) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    async fn matches(&self, process: &Process) -> ProcessResult<bool> {
+        // Cheap attributes go first, as for some platforms,
+        // ex. for Linux, all of them are loaded at once from the single file
+        let mut attributes = Vec::with_capacity(3);
+        if self.parent_pid.is_some() {
+            attributes.push(Attribute::ParentPid);
+        }
+        if self.status.is_some() {
+            attributes.push(Attribute::Status);
+        }
+        if self.has_name_filter() {
+            attributes.push(Attribute::Name);
+        }
+
+        if !attributes.is_empty() {
+            let info = process.snapshot(&attributes).await?;
+
+            if let Some(pid) = self.parent_pid {
+                if !check(info.parent_pid(), |value| *value == pid) {
+                    return Ok(false);
+                }
+            }
+            if let Some(status) = self.status {
+                if !check(info.status(), |value| *value == status) {
+                    return Ok(false);
+                }
+            }
+            if !self.matches_name(&info) {
+                return Ok(false);
+            }
+        }
+
+        if let Some(path) = &self.exe {
+            let info = process.snapshot(&[Attribute::Exe]).await?;
+            if !check(info.exe(), |value| value == path) {
+                return Ok(false);
+            }
+        }
+
+        if let Some(username) = &self.username {
+            let info = process.snapshot(&[Attribute::Username]).await?;
+            if !check(info.username(), |value| value == username) {
+                return Ok(false);
+            }
+        }
+
+        if let Some(substring) = &self.command {
+            let info = process.snapshot(&[Attribute::Command]).await?;
+            if !check(info.command(), |value| {
+                value
+                    .to_os_string()
+                    .to_string_lossy()
+                    .contains(substring.as_str())
+            }) {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Returns a stream over the currently running processes, which are matching
+    /// all the conditions given.
+    ///
+    /// Processes which had exited during the iteration are skipped.
+    pub fn processes(self) -> impl Stream<Item = ProcessResult<Process>> {
+        let finder = Arc::new(self);
+
+        processes()
+            .try_filter_map(move |process| {
+                let finder = finder.clone();
+
+                async move {
+                    if finder.matches(&process).await? {
+                        Ok(Some(process))
+                    } else {
+                        Ok(None)
+                    }
+                }
+            })
+            .filter(|result| match result {
+                Err(ProcessError::NoSuchProcess(..)) => future::ready(false),
+                _ => future::ready(true),
+            })
+    }
+}
+
+/// Returns a builder for the processes lookup, similar to the `pgrep` utility.
+///
+/// Conditions are checked in the order of their loading cost,
+/// so the expensive process attributes are loaded only for the processes
+/// which are matching the cheap conditions already.
+/// Processes, for which any of the required attributes is not available,
+/// ex. because of the insufficient permissions, are not matching.
+///
+/// ## Compatibility
+///
+/// Filtering by the [username] is available for Linux and macOS only,
+/// for Windows no processes are matching that condition.
+///
+/// ## Example
+///
+/// ```rust
+/// # use heim_common::prelude::*;
+/// # use heim_process::{self as process, Process, ProcessResult};
+/// #
+/// # #[heim_derive::main]
+/// # async fn main() -> ProcessResult<()> {
+/// let processes = process::find()
+///     .name("sshd")
+///     .username("root")
+///     .processes();
+/// heim_runtime::pin!(processes);
+///
+/// while let Some(process) = processes.next().await {
+///     println!("{}", process?.pid());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [username]: ./struct.Finder.html#method.username
+pub fn find() -> Finder {
+    Finder::default()
+}
@@ -0,0 +1,181 @@
+use std::path::PathBuf;
+
+use heim_common::units::Time;
+
+use super::{Command, CpuTime, Memory, Status};
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Process attribute, which can be loaded into the [ProcessInfo] snapshot.
+///
+/// Each attribute matches the same-named [Process] method
+/// and shares the same compatibility notes.
+///
+/// [ProcessInfo]: ./struct.ProcessInfo.html
+/// [Process]: ./struct.Process.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Attribute {
+    /// Process parent pid.
+    ParentPid,
+    /// Process name.
+    Name,
+    /// Process executable.
+    Exe,
+    /// Process command line.
+    Command,
+    /// Process current working directory.
+    Cwd,
+    /// Process status.
+    Status,
+    /// Name of the user owning the process.
+    Username,
+    /// Process creation time.
+    CreateTime,
+    /// Accumulated process time.
+    CpuTime,
+    /// Process memory usage.
+    Memory,
+    /// Number of threads used by the process.
+    NumThreads,
+}
+
+/// Snapshot of the process attributes.
+///
+/// Returned by [Process::snapshot] method and [processes_with] function.
+///
+/// Only requested attributes are loaded, all the other ones are `None`.
+/// Attributes, which loading failed with `ProcessError::AccessDenied`
+/// or `ProcessError::ZombieProcess` errors, are containing these errors,
+/// while the rest of the attributes might be loaded successfully.
+///
+/// Attributes, which are not implemented for the current platform yet,
+/// are containing the `ProcessError::Load` error instead of panicking.
+///
+/// [Process::snapshot]: ./struct.Process.html#method.snapshot
+/// [processes_with]: ./fn.processes_with.html
+#[derive(Debug)]
+pub struct ProcessInfo {
+    pub(crate) pid: Pid,
+    pub(crate) parent_pid: Option<ProcessResult<Pid>>,
+    pub(crate) name: Option<ProcessResult<String>>,
+    pub(crate) exe: Option<ProcessResult<PathBuf>>,
+    pub(crate) command: Option<ProcessResult<Command>>,
+    pub(crate) cwd: Option<ProcessResult<PathBuf>>,
+    pub(crate) status: Option<ProcessResult<Status>>,
+    pub(crate) username: Option<ProcessResult<String>>,
+    pub(crate) create_time: Option<ProcessResult<Time>>,
+    pub(crate) cpu_time: Option<ProcessResult<CpuTime>>,
+    pub(crate) memory: Option<ProcessResult<Memory>>,
+    pub(crate) num_threads: Option<ProcessResult<u64>>,
+}
+
+impl ProcessInfo {
+    pub(crate) fn new(pid: Pid) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            parent_pid: None,
+            name: None,
+            exe: None,
+            command: None,
+            cwd: None,
+            status: None,
+            username: None,
+            create_time: None,
+            cpu_time: None,
+            memory: None,
+            num_threads: None,
+        }
+    }
+
+    /// Wraps attribute loading result into the snapshot field.
+    ///
+    /// Errors which are related to this attribute only are stored in the field,
+    /// while the other ones are failing the whole snapshot.
+    pub(crate) fn field<T>(result: ProcessResult<T>) -> ProcessResult<Option<ProcessResult<T>>> {
+        match result {
+            Ok(value) => Ok(Some(Ok(value))),
+            Err(e @ ProcessError::AccessDenied(..)) | Err(e @ ProcessError::ZombieProcess(..)) => {
+                Ok(Some(Err(e)))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Stores the loading error `e` into the `attribute` field.
+    pub(crate) fn set_error(&mut self, attribute: Attribute, e: ProcessError) {
+        match attribute {
+            Attribute::ParentPid => self.parent_pid = Some(Err(e)),
+            Attribute::Name => self.name = Some(Err(e)),
+            Attribute::Exe => self.exe = Some(Err(e)),
+            Attribute::Command => self.command = Some(Err(e)),
+            Attribute::Cwd => self.cwd = Some(Err(e)),
+            Attribute::Status => self.status = Some(Err(e)),
+            Attribute::Username => self.username = Some(Err(e)),
+            Attribute::CreateTime => self.create_time = Some(Err(e)),
+            Attribute::CpuTime => self.cpu_time = Some(Err(e)),
+            Attribute::Memory => self.memory = Some(Err(e)),
+            Attribute::NumThreads => self.num_threads = Some(Err(e)),
+        }
+    }
+
+    /// Returns the process pid.
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    /// Returns process parent pid.
+    pub fn parent_pid(&self) -> Option<&ProcessResult<Pid>> {
+        self.parent_pid.as_ref()
+    }
+
+    /// Returns process name.
+    pub fn name(&self) -> Option<&ProcessResult<String>> {
+        self.name.as_ref()
+    }
+
+    /// Returns process executable as an absolute path.
+    pub fn exe(&self) -> Option<&ProcessResult<PathBuf>> {
+        self.exe.as_ref()
+    }
+
+    /// Returns process command line.
+    pub fn command(&self) -> Option<&ProcessResult<Command>> {
+        self.command.as_ref()
+    }
+
+    /// Returns process current working directory.
+    pub fn cwd(&self) -> Option<&ProcessResult<PathBuf>> {
+        self.cwd.as_ref()
+    }
+
+    /// Returns process status.
+    pub fn status(&self) -> Option<&ProcessResult<Status>> {
+        self.status.as_ref()
+    }
+
+    /// Returns name of the user owning this process.
+    pub fn username(&self) -> Option<&ProcessResult<String>> {
+        self.username.as_ref()
+    }
+
+    /// Returns process creation time, expressed as a [Time] amount since the UNIX epoch.
+    ///
+    /// [Time]: ../units/type.Time.html
+    pub fn create_time(&self) -> Option<&ProcessResult<Time>> {
+        self.create_time.as_ref()
+    }
+
+    /// Returns accumulated process time.
+    pub fn cpu_time(&self) -> Option<&ProcessResult<CpuTime>> {
+        self.cpu_time.as_ref()
+    }
+
+    /// Returns memory usage information for this process.
+    pub fn memory(&self) -> Option<&ProcessResult<Memory>> {
+        self.memory.as_ref()
+    }
+
+    /// Returns the number of threads used by this process.
+    pub fn num_threads(&self) -> Option<&ProcessResult<u64>> {
+        self.num_threads.as_ref()
+    }
+}
@@ -1,25 +1,39 @@
 use std::fmt;
 use std::path::PathBuf;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use heim_common::prelude::*;
 use heim_common::units::Time;
 
-use crate::{sys, Pid, ProcessResult};
+use crate::{sys, Pid, ProcessError, ProcessResult};
 
 mod command;
 mod cpu_times;
 mod cpu_usage;
 mod env;
+mod exit_status;
+mod find;
+mod info;
 mod memory;
+mod open_file;
 mod status;
+mod thread;
+mod tree;
+mod wait;
 
 pub use self::command::{Command, CommandIter};
 pub use self::cpu_times::CpuTime;
-pub use self::cpu_usage::CpuUsage;
+pub use self::cpu_usage::{CpuUsage, CpuUsageTracker};
 pub use self::env::{Environment, EnvironmentIter, IntoEnvironmentIter};
+pub use self::exit_status::ExitStatus;
+pub use self::find::{find, Finder};
+pub use self::info::{Attribute, ProcessInfo};
 pub use self::memory::Memory;
+pub use self::open_file::OpenFile;
 pub use self::status::Status;
+pub use self::thread::Thread;
+pub use self::tree::{tree, ProcessTree};
+pub use self::wait::wait_procs;
 
 /// System process.
 ///
@@ -49,6 +63,31 @@ impl Process {
         get(ppid).await
     }
 
+    /// Returns children processes of this process.
+    ///
+    /// If `recursive` is `true`, all descendants are returned,
+    /// with parents always preceding their children.
+    ///
+    /// Each call builds a new processes [tree] snapshot,
+    /// consider using it directly if multiple processes should be inspected.
+    ///
+    /// [tree]: ./fn.tree.html
+    pub async fn children(&self, recursive: bool) -> ProcessResult<Vec<Process>> {
+        let mut tree = tree().await?;
+        match tree.get(self.pid()) {
+            Some(process) if process == self => {}
+            _ => return Err(ProcessError::NoSuchProcess(self.pid())),
+        }
+
+        let pids = if recursive {
+            tree.descendants(self.pid())
+        } else {
+            tree.children(self.pid()).to_vec()
+        };
+
+        Ok(tree.take(&pids))
+    }
+
     /// Returns process name.
     pub async fn name(&self) -> ProcessResult<String> {
         self.as_ref().name().await
@@ -92,6 +131,22 @@ impl Process {
         self.as_ref().cwd().await
     }
 
+    /// Returns name of the user owning this process.
+    ///
+    /// For *nix systems real user ID of the process is resolved into the user name;
+    /// if there is no such user in the system, user ID itself is returned as a string.
+    ///
+    /// See [`os::unix::ProcessExt`] for the user and group IDs.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Windows this method is not implemented yet and will always return an error.
+    ///
+    /// [`os::unix::ProcessExt`]: ./os/unix/trait.ProcessExt.html
+    pub async fn username(&self) -> ProcessResult<String> {
+        self.as_ref().username().await
+    }
+
     /// Returns current process status.
     pub async fn status(&self) -> ProcessResult<Status> {
         self.as_ref().status().await
@@ -165,6 +220,72 @@ impl Process {
         self.as_ref().memory().await.map(Into::into)
     }
 
+    /// Returns a stream over the regular files opened by this process.
+    ///
+    /// Sockets, pipes and other non-file descriptors are not included.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Windows this method is not implemented yet and will return an empty stream.
+    pub fn open_files(&self) -> impl Stream<Item = ProcessResult<OpenFile>> {
+        self.as_ref().open_files().map_ok(Into::into)
+    }
+
+    /// Returns the number of file descriptors currently opened by this process.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Windows this method is not implemented yet and will always return an error.
+    pub async fn num_fds(&self) -> ProcessResult<usize> {
+        self.as_ref().num_fds().await
+    }
+
+    /// Returns a stream over the threads of this process.
+    ///
+    /// ## Compatibility
+    ///
+    /// For macOS and Windows this method is not implemented yet and will return an empty stream.
+    pub fn threads(&self) -> impl Stream<Item = ProcessResult<Thread>> {
+        self.as_ref().threads().map_ok(Into::into)
+    }
+
+    /// Returns the number of threads used by this process.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Windows this method is not implemented yet and will always return an error.
+    pub async fn num_threads(&self) -> ProcessResult<u64> {
+        self.as_ref().num_threads().await
+    }
+
+    /// Loads the requested process `attributes` at once.
+    ///
+    /// Compared to calling the corresponding methods one by one,
+    /// data shared by multiple attributes is loaded only once,
+    /// ex. for Linux `/proc/<pid>/stat` file is read and parsed just one time.
+    ///
+    /// See [`ProcessInfo`] for details on how the errors are handled.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use heim_process::{self as process, Attribute, Process, ProcessResult};
+    /// #
+    /// # #[heim_derive::main]
+    /// # async fn main() -> ProcessResult<()> {
+    /// let process = process::current().await?;
+    /// let info = process.snapshot(&[Attribute::Name, Attribute::Status]).await?;
+    ///
+    /// println!("Name: {:?}, status: {:?}", info.name(), info.status());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ProcessInfo`]: ./struct.ProcessInfo.html
+    pub async fn snapshot(&self, attributes: &[Attribute]) -> ProcessResult<ProcessInfo> {
+        self.as_ref().snapshot(attributes).await
+    }
+
     /// Checks if this `Process` is still running.
     pub async fn is_running(&self) -> ProcessResult<bool> {
         self.as_ref().is_running().await
@@ -232,6 +353,48 @@ impl Process {
     pub async fn kill(&self) -> ProcessResult<()> {
         self.as_ref().kill().await
     }
+
+    /// Checks if this process had exited, without blocking.
+    ///
+    /// Returns `None` if the process is still running.
+    /// See [`Process::wait`] for details on the returned [`ExitStatus`].
+    ///
+    /// [`Process::wait`]: #method.wait
+    /// [`ExitStatus`]: ./struct.ExitStatus.html
+    pub async fn try_wait(&self) -> ProcessResult<Option<ExitStatus>> {
+        self.as_ref().try_wait().await
+    }
+
+    /// Waits for this process to exit.
+    ///
+    /// Returns `None` if the process is still running after the `timeout` given,
+    /// if `timeout` is `None`, waits until the process is gone.
+    ///
+    /// If this process is a child of the current process, it is reaped
+    /// and its exit status is returned; for other processes [`ExitStatus`]
+    /// does not contain any information, as it is not available to us.
+    ///
+    /// Process exit is checked periodically with an increasing delay between the checks,
+    /// so the returned future might resolve a bit later after the actual process exit.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Windows exit status is never available.
+    ///
+    /// [`ExitStatus`]: ./struct.ExitStatus.html
+    pub async fn wait(&self, timeout: Option<Duration>) -> ProcessResult<Option<ExitStatus>> {
+        let mut backoff = wait::Backoff::new(timeout);
+
+        loop {
+            if let Some(status) = self.try_wait().await? {
+                return Ok(Some(status));
+            }
+
+            if !backoff.sleep().await {
+                return Ok(None);
+            }
+        }
+    }
 }
 
 impl fmt::Debug for Process {
@@ -245,6 +408,34 @@ pub fn processes() -> impl Stream<Item = ProcessResult<Process>> {
     sys::processes().map_ok(Into::into)
 }
 
+/// Returns a stream over the currently running processes
+/// along with the snapshot of their `attributes`.
+///
+/// Processes which had exited during the iteration are skipped.
+/// See [`Process::snapshot`] for details.
+///
+/// [`Process::snapshot`]: ./struct.Process.html#method.snapshot
+pub fn processes_with(
+    attributes: &[Attribute],
+) -> impl Stream<Item = ProcessResult<(Process, ProcessInfo)>> {
+    let attributes = attributes.to_vec();
+
+    processes()
+        .and_then(move |process| {
+            let attributes = attributes.clone();
+
+            async move {
+                let info = process.snapshot(&attributes).await?;
+
+                Ok((process, info))
+            }
+        })
+        .filter(|result| match result {
+            Err(ProcessError::NoSuchProcess(..)) => future::ready(false),
+            _ => future::ready(true),
+        })
+}
+
 /// Loads the process information with `pid` given.
 pub async fn get(pid: Pid) -> ProcessResult<Process> {
     sys::get(pid).await.map(Into::into)
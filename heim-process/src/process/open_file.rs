@@ -0,0 +1,51 @@
+use std::fmt;
+use std::path::Path;
+
+use heim_common::prelude::wrap;
+
+use crate::sys;
+
+/// File opened by the process.
+pub struct OpenFile(sys::OpenFile);
+
+wrap!(OpenFile, sys::OpenFile);
+
+impl OpenFile {
+    /// Returns file descriptor number.
+    pub fn fd(&self) -> i32 {
+        self.as_ref().fd()
+    }
+
+    /// Returns absolute path to the opened file.
+    pub fn path(&self) -> &Path {
+        self.as_ref().path()
+    }
+
+    /// Returns current file offset.
+    pub fn position(&self) -> u64 {
+        self.as_ref().position()
+    }
+
+    /// Returns flags the file was opened with.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Linux it is the `open(2)` flags value, as reported by `/proc/<pid>/fdinfo/<fd>`.
+    ///
+    /// For macOS it is the kernel `FREAD`/`FWRITE`-style flags value,
+    /// which is the `open(2)` access mode incremented by one.
+    pub fn flags(&self) -> u32 {
+        self.as_ref().flags()
+    }
+}
+
+impl fmt::Debug for OpenFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OpenFile")
+            .field("fd", &self.fd())
+            .field("path", &self.path())
+            .field("position", &self.position())
+            .field("flags", &self.flags())
+            .finish()
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+
+use heim_common::prelude::wrap;
+
+use crate::{sys, CpuTime, Pid, Status};
+
+/// Process thread.
+pub struct Thread(sys::Thread);
+
+wrap!(Thread, sys::Thread);
+
+impl Thread {
+    /// Returns thread ID.
+    pub fn id(&self) -> Pid {
+        self.as_ref().id()
+    }
+
+    /// Returns thread name.
+    pub fn name(&self) -> &str {
+        self.as_ref().name()
+    }
+
+    /// Returns thread status.
+    pub fn status(&self) -> Status {
+        self.as_ref().status()
+    }
+
+    /// Returns accumulated thread CPU time.
+    pub fn cpu_time(&self) -> &CpuTime {
+        self.as_ref().cpu_time()
+    }
+}
+
+impl fmt::Debug for Thread {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Thread")
+            .field("id", &self.id())
+            .field("name", &self.name())
+            .field("status", &self.status())
+            .field("cpu_time", &self.cpu_time())
+            .finish()
+    }
+}
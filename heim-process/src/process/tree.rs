@@ -0,0 +1,158 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use super::{processes, Process};
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Snapshot of the processes hierarchy.
+///
+/// Built with a single pass over the running processes by the [tree] function.
+///
+/// Parent-child relations are checked not only by the parent PID,
+/// but by the processes creation time also, so the process which PID was re-used
+/// will not be considered as a parent for the processes spawned before it.
+///
+/// [tree]: ./fn.tree.html
+#[derive(Debug, Default)]
+pub struct ProcessTree {
+    processes: HashMap<Pid, Process>,
+    children: HashMap<Pid, Vec<Pid>>,
+}
+
+impl ProcessTree {
+    /// Returns process with `pid` given, if it was running during the snapshot.
+    pub fn get(&self, pid: Pid) -> Option<&Process> {
+        self.processes.get(&pid)
+    }
+
+    /// Returns iterator over all processes in this snapshot.
+    pub fn processes(&self) -> impl Iterator<Item = &Process> {
+        self.processes.values()
+    }
+
+    /// Returns PIDs of the processes, which do not have any known parent.
+    pub fn roots(&self) -> Vec<Pid> {
+        let children = self.children.values().flatten().collect::<HashSet<_>>();
+        let mut roots = self
+            .processes
+            .keys()
+            .filter(|pid| !children.contains(pid))
+            .cloned()
+            .collect::<Vec<_>>();
+        roots.sort();
+
+        roots
+    }
+
+    /// Returns PIDs of the direct children for process with `pid` given.
+    pub fn children(&self, pid: Pid) -> &[Pid] {
+        self.children
+            .get(&pid)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns PIDs of all descendants for process with `pid` given.
+    ///
+    /// Parents are always preceding their children in the returned list,
+    /// so it can be reversed in order to signal the whole subtree bottom-up.
+    pub fn descendants(&self, pid: Pid) -> Vec<Pid> {
+        let mut result = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(pid);
+
+        while let Some(parent) = queue.pop_front() {
+            for child in self.children(parent) {
+                result.push(*child);
+                queue.push_back(*child);
+            }
+        }
+
+        result
+    }
+
+    /// Removes processes with `pids` given from this snapshot and returns them.
+    pub(crate) fn take(&mut self, pids: &[Pid]) -> Vec<Process> {
+        pids.iter()
+            .filter_map(|pid| self.processes.remove(pid))
+            .collect()
+    }
+}
+
+/// Builds the [snapshot] of the currently running processes hierarchy.
+///
+/// Processes which are exited during the snapshot creation or which parent PID
+/// can't be fetched due to insufficient permissions are silently skipped.
+///
+/// [snapshot]: ./struct.ProcessTree.html
+pub async fn tree() -> ProcessResult<ProcessTree> {
+    let mut tree = ProcessTree::default();
+    let mut parents = Vec::new();
+
+    let processes = processes();
+    rt::pin!(processes);
+    while let Some(process) = processes.next().await {
+        let process = match process {
+            Ok(process) => process,
+            Err(ProcessError::Load(e)) => return Err(ProcessError::Load(e)),
+            Err(..) => continue,
+        };
+        let ppid = match process.parent_pid().await {
+            Ok(ppid) => ppid,
+            Err(ProcessError::Load(e)) => return Err(ProcessError::Load(e)),
+            Err(..) => continue,
+        };
+
+        parents.push((process.pid(), ppid));
+        let _ = tree.processes.insert(process.pid(), process);
+    }
+
+    for (pid, ppid) in parents {
+        // Some systems are reporting process as a parent of itself (ex. PID 0)
+        if pid == ppid {
+            continue;
+        }
+        let (child, parent) = match (tree.processes.get(&pid), tree.processes.get(&ppid)) {
+            (Some(child), Some(parent)) => (child, parent),
+            _ => continue,
+        };
+        // Parent PID might be re-used by the newer process already
+        if child.create_time().await? < parent.create_time().await? {
+            continue;
+        }
+
+        tree.children.entry(ppid).or_insert_with(Vec::new).push(pid);
+    }
+
+    for children in tree.children.values_mut() {
+        children.sort();
+    }
+
+    Ok(tree)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::ProcessTree;
+
+    #[test]
+    fn test_descendants() {
+        let mut children = HashMap::new();
+        let _ = children.insert(1, vec![2, 3]);
+        let _ = children.insert(2, vec![4]);
+        let _ = children.insert(4, vec![5]);
+        let tree = ProcessTree {
+            processes: HashMap::new(),
+            children,
+        };
+
+        assert_eq!(tree.children(1), &[2, 3]);
+        assert!(tree.children(3).is_empty());
+        assert_eq!(tree.descendants(1), vec![2, 3, 4, 5]);
+        assert_eq!(tree.descendants(4), vec![5]);
+    }
+}
@@ -0,0 +1,104 @@
+use std::cmp;
+use std::time::{Duration, Instant};
+
+use futures_timer::Delay;
+
+use super::{ExitStatus, Process};
+use crate::ProcessResult;
+
+const MIN_DELAY: Duration = Duration::from_micros(100);
+const MAX_DELAY: Duration = Duration::from_millis(40);
+
+/// Exponentially growing delay between the process exit checks.
+#[derive(Debug)]
+pub(crate) struct Backoff {
+    delay: Duration,
+    deadline: Option<Instant>,
+}
+
+impl Backoff {
+    pub fn new(timeout: Option<Duration>) -> Backoff {
+        Backoff {
+            delay: MIN_DELAY,
+            deadline: timeout.map(|timeout| Instant::now() + timeout),
+        }
+    }
+
+    /// Sleeps until the next check, returns `false` if the deadline was reached already.
+    pub async fn sleep(&mut self) -> bool {
+        let delay = match self.deadline {
+            Some(deadline) => {
+                let now = Instant::now();
+                if now >= deadline {
+                    return false;
+                }
+
+                cmp::min(self.delay, deadline - now)
+            }
+            None => self.delay,
+        };
+
+        Delay::new(delay).await;
+        self.delay = cmp::min(self.delay * 2, MAX_DELAY);
+
+        true
+    }
+}
+
+/// Waits for multiple processes to exit.
+///
+/// Returns processes which had exited along with their [exit statuses]
+/// and processes which are still running after the `timeout` given.
+/// If `timeout` is `None`, waits until all processes are gone.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use heim_process::{self as process, Process, ProcessResult};
+/// #
+/// # #[heim_derive::main]
+/// # async fn main() -> ProcessResult<()> {
+/// let children = process::current().await?.children(false).await?;
+/// for child in &children {
+///     child.terminate().await?;
+/// }
+///
+/// let (gone, alive) = process::wait_procs(children, Some(Duration::from_secs(3))).await?;
+/// for (process, status) in &gone {
+///     println!("{:?} exited with {:?}", process, status);
+/// }
+/// for process in &alive {
+///     process.kill().await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [exit statuses]: ./struct.ExitStatus.html
+pub async fn wait_procs<I>(
+    processes: I,
+    timeout: Option<Duration>,
+) -> ProcessResult<(Vec<(Process, ExitStatus)>, Vec<Process>)>
+where
+    I: IntoIterator<Item = Process>,
+{
+    let mut gone = Vec::new();
+    let mut alive = processes.into_iter().collect::<Vec<_>>();
+    let mut backoff = Backoff::new(timeout);
+
+    loop {
+        let mut still_alive = Vec::with_capacity(alive.len());
+        for process in alive {
+            match process.try_wait().await? {
+                Some(status) => gone.push((process, status)),
+                None => still_alive.push(process),
+            }
+        }
+        alive = still_alive;
+
+        if alive.is_empty() || !backoff.sleep().await {
+            return Ok((gone, alive));
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use ordered_float::NotNan;
 
-use crate::Pid;
+use super::Process;
+use crate::{Attribute, Pid, ProcessInfo, ProcessResult};
 use heim_common::units::{time, Time};
 
 /// Process unique ID.
@@ -47,3 +48,36 @@ impl UniqueId {
         Time::new::<time::second>(*self.create_time)
     }
 }
+
+/// Loads the process attribute with the corresponding accessor method.
+pub async fn load_attribute(
+    process: &Process,
+    attribute: Attribute,
+    info: &mut ProcessInfo,
+) -> ProcessResult<()> {
+    match attribute {
+        Attribute::ParentPid => info.parent_pid = ProcessInfo::field(process.parent_pid().await)?,
+        Attribute::Name => info.name = ProcessInfo::field(process.name().await)?,
+        Attribute::Exe => info.exe = ProcessInfo::field(process.exe().await)?,
+        Attribute::Command => {
+            info.command = ProcessInfo::field(process.command().await.map(Into::into))?
+        }
+        Attribute::Cwd => info.cwd = ProcessInfo::field(process.cwd().await)?,
+        Attribute::Status => info.status = ProcessInfo::field(process.status().await)?,
+        Attribute::Username => info.username = ProcessInfo::field(process.username().await)?,
+        Attribute::CreateTime => {
+            info.create_time = ProcessInfo::field(process.create_time().await)?
+        }
+        Attribute::CpuTime => {
+            info.cpu_time = ProcessInfo::field(process.cpu_time().await.map(Into::into))?
+        }
+        Attribute::Memory => {
+            info.memory = ProcessInfo::field(process.memory().await.map(Into::into))?
+        }
+        Attribute::NumThreads => {
+            info.num_threads = ProcessInfo::field(process.num_threads().await)?
+        }
+    }
+
+    Ok(())
+}
@@ -8,20 +8,73 @@ use heim_common::units::Time;
 use heim_runtime as rt;
 
 use super::{pid_exists, pids};
-use crate::os::linux::IoCounters;
-use crate::os::unix::Signal;
-use crate::sys::common::UniqueId;
-use crate::sys::unix::pid_kill;
-use crate::{Pid, ProcessError, ProcessResult, Status};
-
+use crate::os::linux::{
+    pidfd_enabled, CtxSwitches, FullMemory, IoCounters, IoPriority, MemoryMap, PageFaults,
+    Resource, Rlimit, SchedPolicy,
+};
+use crate::os::unix::{Gids, Signal, Uids};
+use crate::sys::common::{load_attribute, UniqueId};
+use crate::sys::unix::{pgid_kill, pid_kill, pid_set_nice, pid_try_wait, terminal, username};
+use crate::{Attribute, ExitStatus, Pid, ProcessError, ProcessInfo, ProcessResult, Status};
+
+mod pidfd;
 mod procfs;
+mod rlimit;
+mod sched;
+
+pub use self::procfs::{Command, CommandIter, CpuTime, Environment, Memory, OpenFile, Thread};
+
+/// Attributes, which are loaded from the `/proc/<pid>/stat` file.
+const STAT_ATTRIBUTES: &[Attribute] = &[
+    Attribute::ParentPid,
+    Attribute::Name,
+    Attribute::Status,
+    Attribute::CpuTime,
+    Attribute::NumThreads,
+];
+
+/// Checks if the `comm` value from the `/proc/<pid>/stat` file is truncated.
+fn is_truncated(name: &str) -> bool {
+    // TODO: Move `15` to the const
+    name.len() >= 15
+}
+
+/// Looks up the full process name in its command line for the truncated `comm` value.
+fn full_name(name: String, command: &Command) -> String {
+    // There might be an absolute path to executable
+    let path = command
+        .into_iter()
+        .next()
+        .map(Path::new)
+        .and_then(Path::file_name);
+
+    match path {
+        // We can assume that on Linux paths and filenames are UTF-8,
+        // and since OsStr does not has the `starts_with` method,
+        // we could compare raw bytes
+        Some(exe) if exe.as_bytes().starts_with(name.as_bytes()) => {
+            exe.to_string_lossy().into_owned()
+        }
+        _ => name,
+    }
+}
 
-pub use self::procfs::{Command, CommandIter, CpuTime, Environment, Memory};
+/// Copies the attribute loading error, which is shared by multiple snapshot fields.
+///
+/// Only the errors which can be stored in the `ProcessInfo` fields are expected here.
+fn copy_error(e: &ProcessError) -> ProcessError {
+    match *e {
+        ProcessError::AccessDenied(pid) => ProcessError::AccessDenied(pid),
+        ProcessError::ZombieProcess(pid) => ProcessError::ZombieProcess(pid),
+        _ => unreachable!(),
+    }
+}
 
 #[derive(Debug)]
 pub struct Process {
     pid: Pid,
     unique_id: UniqueId,
+    pidfd: Option<pidfd::PidFd>,
 }
 
 impl Process {
@@ -38,29 +91,11 @@ impl Process {
     pub async fn name(&self) -> ProcessResult<String> {
         let procfs::Stat { name, .. } = procfs::stat(self.pid).await?;
 
-        // TODO: Move `15` to the const
-        if name.len() >= 15 {
-            let command = match procfs::command(self.pid).await {
-                Ok(command) => command,
+        if is_truncated(&name) {
+            match procfs::command(self.pid).await {
+                Ok(command) => Ok(full_name(name, &command)),
                 // Reading process command might fail, so we should better fall back to what we got
-                Err(..) => return Ok(name),
-            };
-
-            // There might be an absolute path to executable
-            let path = command
-                .into_iter()
-                .next()
-                .map(Path::new)
-                .and_then(Path::file_name);
-
-            match path {
-                // We can assume that on Linux paths and filenames are UTF-8,
-                // and since OsStr does not has the `starts_with` method,
-                // we could compare raw bytes
-                Some(exe) if exe.as_bytes().starts_with(name.as_bytes()) => {
-                    Ok(exe.to_string_lossy().into_owned())
-                }
-                _ => Ok(name),
+                Err(..) => Ok(name),
             }
         } else {
             Ok(name)
@@ -68,7 +103,7 @@ impl Process {
     }
 
     pub async fn exe(&self) -> ProcessResult<PathBuf> {
-        match rt::fs::read_link(format!("/proc/{}/exe", self.pid)).await {
+        match rt::fs::read_link(rt::fs::proc_path(format!("{}/exe", self.pid))).await {
             Ok(path) => Ok(path),
             Err(..) => {
                 // log::trace!() ?
@@ -88,7 +123,7 @@ impl Process {
     }
 
     pub async fn cwd(&self) -> ProcessResult<PathBuf> {
-        match rt::fs::read_link(format!("/proc/{}/cwd", self.pid)).await {
+        match rt::fs::read_link(rt::fs::proc_path(format!("{}/cwd", self.pid))).await {
             Ok(path) => Ok(path),
             Err(..) => {
                 if pid_exists(self.pid).await? {
@@ -114,21 +149,205 @@ impl Process {
         Ok(self.unique_id.create_time())
     }
 
+    pub fn unique_id(&self) -> &UniqueId {
+        &self.unique_id
+    }
+
     pub async fn cpu_time(&self) -> ProcessResult<CpuTime> {
-        procfs::stat(self.pid).await.map(Into::into)
+        let stat = procfs::stat(self.pid).await?;
+
+        Ok(CpuTime::from(&stat))
     }
 
     pub async fn memory(&self) -> ProcessResult<Memory> {
         procfs::stat_memory(self.pid).await
     }
 
+    pub fn open_files(&self) -> impl Stream<Item = ProcessResult<OpenFile>> {
+        procfs::open_files(self.pid)
+    }
+
+    pub async fn num_fds(&self) -> ProcessResult<usize> {
+        procfs::num_fds(self.pid).await
+    }
+
+    pub fn threads(&self) -> impl Stream<Item = ProcessResult<Thread>> {
+        procfs::threads(self.pid)
+    }
+
+    pub async fn num_threads(&self) -> ProcessResult<u64> {
+        let procfs::Stat { num_threads, .. } = procfs::stat(self.pid).await?;
+
+        Ok(num_threads)
+    }
+
+    pub async fn username(&self) -> ProcessResult<String> {
+        let uids = self.uids().await?;
+
+        username(uids.real()).await
+    }
+
+    pub async fn uids(&self) -> ProcessResult<Uids> {
+        let procfs::ProcStatus { uids, .. } = procfs::status(self.pid).await?;
+
+        Ok(uids)
+    }
+
+    pub async fn gids(&self) -> ProcessResult<Gids> {
+        let procfs::ProcStatus { gids, .. } = procfs::status(self.pid).await?;
+
+        Ok(gids)
+    }
+
+    pub async fn groups(&self) -> ProcessResult<Vec<u32>> {
+        let procfs::ProcStatus { groups, .. } = procfs::status(self.pid).await?;
+
+        Ok(groups)
+    }
+
+    pub async fn nice(&self) -> ProcessResult<i32> {
+        let procfs::Stat { nice, .. } = procfs::stat(self.pid).await?;
+
+        Ok(nice)
+    }
+
+    pub async fn set_nice(&self, value: i32) -> ProcessResult<()> {
+        if self.is_running().await? {
+            pid_set_nice(self.pid, value)
+        } else {
+            Err(ProcessError::NoSuchProcess(self.pid))
+        }
+    }
+
+    pub async fn terminal(&self) -> ProcessResult<Option<PathBuf>> {
+        let procfs::Stat { tty_nr, .. } = procfs::stat(self.pid).await?;
+
+        if tty_nr == 0 {
+            Ok(None)
+        } else {
+            // Device number is encoded in the same way as the `st_rdev` field
+            terminal(u64::from(tty_nr as u32)).await
+        }
+    }
+
+    pub async fn pgid(&self) -> ProcessResult<Pid> {
+        let procfs::Stat { pgrp, .. } = procfs::stat(self.pid).await?;
+
+        Ok(pgrp)
+    }
+
+    pub async fn sid(&self) -> ProcessResult<Pid> {
+        let procfs::Stat { session_id, .. } = procfs::stat(self.pid).await?;
+
+        Ok(session_id)
+    }
+
+    pub async fn tpgid(&self) -> ProcessResult<Option<Pid>> {
+        let procfs::Stat { tpgid, .. } = procfs::stat(self.pid).await?;
+
+        // `-1` stands for the process without controlling terminal
+        if tpgid > 0 {
+            Ok(Some(tpgid))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub async fn signal_group(&self, signal: Signal) -> ProcessResult<()> {
+        if self.is_running().await? {
+            let pgid = self.pgid().await?;
+
+            pgid_kill(pgid, signal)
+        } else {
+            Err(ProcessError::NoSuchProcess(self.pid))
+        }
+    }
+
+    pub async fn snapshot(&self, attributes: &[Attribute]) -> ProcessResult<ProcessInfo> {
+        let mut info = ProcessInfo::new(self.pid);
+
+        // Most of the attributes are coming from the same file, so it is read only once,
+        // and its loading error is stored in all the attributes depending on it
+        let stat = if attributes
+            .iter()
+            .any(|attribute| STAT_ATTRIBUTES.contains(attribute))
+        {
+            ProcessInfo::field(procfs::stat(self.pid).await)?
+        } else {
+            None
+        };
+
+        // Command line is needed both for the `Command` attribute
+        // and for the truncated process name, so it is read only once too
+        let name_needs_command = attributes.contains(&Attribute::Name)
+            && match &stat {
+                Some(Ok(stat)) => is_truncated(&stat.name),
+                _ => false,
+            };
+        let mut command = if name_needs_command || attributes.contains(&Attribute::Command) {
+            Some(procfs::command(self.pid).await)
+        } else {
+            None
+        };
+
+        // Resolved before the loop, as the command line might be moved into the snapshot
+        let mut name = match (&stat, &command) {
+            (Some(Ok(stat)), Some(Ok(command))) if name_needs_command => {
+                Some(full_name(stat.name.clone(), command))
+            }
+            // Falling back to the truncated name, same as `Process::name` does
+            (Some(Ok(stat)), _) => Some(stat.name.clone()),
+            _ => None,
+        };
+
+        for &attribute in attributes {
+            if attribute == Attribute::Command {
+                if let Some(command) = command.take() {
+                    info.command = ProcessInfo::field(command.map(Into::into))?;
+                }
+                continue;
+            }
+
+            let stat = match (STAT_ATTRIBUTES.contains(&attribute), stat.as_ref()) {
+                (true, Some(Ok(stat))) => stat,
+                (true, Some(Err(e))) => {
+                    info.set_error(attribute, copy_error(e));
+                    continue;
+                }
+                _ => {
+                    load_attribute(self, attribute, &mut info).await?;
+                    continue;
+                }
+            };
+
+            match attribute {
+                Attribute::ParentPid => info.parent_pid = Some(Ok(stat.ppid)),
+                Attribute::Name => info.name = name.take().map(Ok),
+                Attribute::Status => info.status = Some(Ok(stat.state)),
+                Attribute::CpuTime => info.cpu_time = Some(Ok(CpuTime::from(stat).into())),
+                Attribute::NumThreads => info.num_threads = Some(Ok(stat.num_threads)),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(info)
+    }
+
     pub async fn is_running(&self) -> ProcessResult<bool> {
-        let other = get(self.pid).await?;
+        if let Some(pidfd) = &self.pidfd {
+            return pidfd.is_running();
+        }
+
+        let procfs::Stat { create_time, .. } = procfs::stat(self.pid).await?;
 
-        Ok(other == *self)
+        Ok(UniqueId::new(self.pid, create_time) == self.unique_id)
     }
 
     pub async fn _signal(&self, signal: Signal) -> ProcessResult<()> {
+        if let Some(pidfd) = &self.pidfd {
+            return pidfd.signal(signal);
+        }
+
         if self.is_running().await? {
             pid_kill(self.pid, signal)
         } else {
@@ -156,6 +375,25 @@ impl Process {
         self.signal(Signal::Kill).await
     }
 
+    pub async fn try_wait(&self) -> ProcessResult<Option<ExitStatus>> {
+        if let Some(pidfd) = &self.pidfd {
+            return if pidfd.has_exited()? {
+                // Exited process holds its PID until reaped, so it is safe to use here
+                Ok(Some(pid_try_wait(self.pid)?.unwrap_or_default()))
+            } else {
+                Ok(None)
+            };
+        }
+
+        // Checking first if process is still running, as the PID could be re-used
+        // by some other child of the current process, which should not be reaped.
+        match self.is_running().await {
+            Ok(true) => pid_try_wait(self.pid),
+            Ok(false) | Err(ProcessError::NoSuchProcess(..)) => Ok(Some(ExitStatus::default())),
+            Err(e) => Err(e),
+        }
+    }
+
     // Linux-specific methods
 
     pub async fn io_counters(&self) -> ProcessResult<IoCounters> {
@@ -167,6 +405,133 @@ impl Process {
             .map_err(Into::into)
             .boxed()
     }
+
+    pub async fn full_memory(&self) -> ProcessResult<FullMemory> {
+        procfs::full_memory(self.pid).await
+    }
+
+    pub fn memory_maps(&self) -> BoxStream<ProcessResult<MemoryMap>> {
+        procfs::memory_maps(self.pid).boxed()
+    }
+
+    pub async fn ctx_switches(&self) -> ProcessResult<CtxSwitches> {
+        let procfs::ProcStatus {
+            voluntary_ctxt_switches,
+            nonvoluntary_ctxt_switches,
+            ..
+        } = procfs::status(self.pid).await?;
+
+        match (voluntary_ctxt_switches, nonvoluntary_ctxt_switches) {
+            (Some(voluntary), Some(involuntary)) => Ok(CtxSwitches {
+                voluntary,
+                involuntary,
+            }),
+            _ => Err(Error::missing_key("voluntary_ctxt_switches", "/proc/<pid>/status").into()),
+        }
+    }
+
+    pub async fn page_faults(&self) -> ProcessResult<PageFaults> {
+        let procfs::Stat { minflt, majflt, .. } = procfs::stat(self.pid).await?;
+
+        Ok(PageFaults {
+            minor: minflt,
+            major: majflt,
+        })
+    }
+
+    pub async fn priority(&self) -> ProcessResult<i32> {
+        let procfs::Stat { priority, .. } = procfs::stat(self.pid).await?;
+
+        Ok(priority)
+    }
+
+    pub async fn rt_priority(&self) -> ProcessResult<u32> {
+        let procfs::Stat { rt_priority, .. } = procfs::stat(self.pid).await?;
+
+        Ok(rt_priority)
+    }
+
+    pub async fn processor(&self) -> ProcessResult<usize> {
+        let procfs::Stat { processor, .. } = procfs::stat(self.pid).await?;
+
+        Ok(processor)
+    }
+
+    pub async fn blkio_delay(&self) -> ProcessResult<Time> {
+        let procfs::Stat {
+            delayacct_blkio, ..
+        } = procfs::stat(self.pid).await?;
+
+        Ok(delayacct_blkio)
+    }
+
+    pub async fn cpu_affinity(&self) -> ProcessResult<Vec<usize>> {
+        sched::cpu_affinity(self.pid)
+    }
+
+    pub async fn set_cpu_affinity(&self, cpus: &[usize]) -> ProcessResult<()> {
+        if self.is_running().await? {
+            sched::set_cpu_affinity(self.pid, cpus)
+        } else {
+            Err(ProcessError::NoSuchProcess(self.pid))
+        }
+    }
+
+    pub async fn sched_policy(&self) -> ProcessResult<SchedPolicy> {
+        sched::sched_policy(self.pid)
+    }
+
+    pub async fn sched_priority(&self) -> ProcessResult<i32> {
+        sched::sched_priority(self.pid)
+    }
+
+    pub async fn set_sched_policy(&self, policy: SchedPolicy, priority: i32) -> ProcessResult<()> {
+        if self.is_running().await? {
+            sched::set_sched_policy(self.pid, policy, priority)
+        } else {
+            Err(ProcessError::NoSuchProcess(self.pid))
+        }
+    }
+
+    pub async fn io_priority(&self) -> ProcessResult<IoPriority> {
+        sched::io_priority(self.pid)
+    }
+
+    pub async fn set_io_priority(&self, priority: IoPriority) -> ProcessResult<()> {
+        if self.is_running().await? {
+            sched::set_io_priority(self.pid, priority)
+        } else {
+            Err(ProcessError::NoSuchProcess(self.pid))
+        }
+    }
+
+    pub async fn rlimit(&self, resource: Resource) -> ProcessResult<Rlimit> {
+        rlimit::rlimit(self.pid, resource).await
+    }
+
+    pub async fn set_rlimit(&self, resource: Resource, limit: Rlimit) -> ProcessResult<()> {
+        if self.is_running().await? {
+            rlimit::set_rlimit(self.pid, resource, limit)
+        } else {
+            Err(ProcessError::NoSuchProcess(self.pid))
+        }
+    }
+
+    pub fn connections(
+        &self,
+        kind: heim_net::ConnectionKind,
+    ) -> BoxStream<ProcessResult<heim_net::Connection>> {
+        use heim_net::os::linux::ConnectionExt;
+
+        procfs::socket_inodes(self.pid)
+            .map_ok(move |inodes| {
+                heim_net::connections(kind)
+                    .map_err(ProcessError::from)
+                    .try_filter(move |conn| future::ready(inodes.contains(&conn.inode())))
+            })
+            .try_flatten_stream()
+            .boxed()
+    }
 }
 
 impl hash::Hash for Process {
@@ -188,11 +553,26 @@ pub fn processes() -> impl Stream<Item = ProcessResult<Process>> {
 }
 
 pub async fn get(pid: Pid) -> ProcessResult<Process> {
+    let pidfd = if pidfd_enabled() {
+        pidfd::PidFd::open(pid)?
+    } else {
+        None
+    };
+
     let procfs::Stat { create_time, .. } = procfs::stat(pid).await?;
 
+    // pidfd is opened before the stat read, so if the process was reaped in between
+    // and its PID was re-used already, loaded information belongs to some other process
+    if let Some(pidfd) = &pidfd {
+        if !pidfd.is_running()? {
+            return Err(ProcessError::NoSuchProcess(pid));
+        }
+    }
+
     Ok(Process {
         pid,
         unique_id: UniqueId::new(pid, create_time),
+        pidfd,
     })
 }
 
@@ -0,0 +1,102 @@
+use std::io;
+use std::os::unix::io::RawFd;
+use std::ptr;
+
+use heim_common::Error;
+
+use crate::os::unix::Signal;
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Owned process file descriptor, see `pidfd_open(2)`.
+#[derive(Debug)]
+pub struct PidFd {
+    pid: Pid,
+    fd: RawFd,
+}
+
+impl PidFd {
+    /// Opens pidfd for the `pid` process.
+    ///
+    /// Returns `None` if pidfds are not supported by the running kernel.
+    pub fn open(pid: Pid) -> ProcessResult<Option<PidFd>> {
+        let result = unsafe { libc::syscall(libc::SYS_pidfd_open, pid, 0) };
+
+        if result >= 0 {
+            return Ok(Some(PidFd {
+                pid,
+                fd: result as RawFd,
+            }));
+        }
+
+        let e = io::Error::last_os_error();
+        match e.raw_os_error() {
+            Some(libc::ESRCH) => Err(ProcessError::NoSuchProcess(pid)),
+            // `EINVAL` is returned for the non-leader threads by kernels older than 6.9
+            Some(libc::ENOSYS) | Some(libc::EINVAL) => Ok(None),
+            _ => Err(Error::from(e).with_ffi("pidfd_open").into()),
+        }
+    }
+
+    fn send(&self, signal: libc::c_int) -> ProcessResult<()> {
+        let result = unsafe {
+            libc::syscall(
+                libc::SYS_pidfd_send_signal,
+                self.fd,
+                signal,
+                ptr::null::<libc::siginfo_t>(),
+                0,
+            )
+        };
+
+        if result == 0 {
+            Ok(())
+        } else {
+            let e = io::Error::last_os_error();
+            match e.raw_os_error() {
+                Some(libc::ESRCH) => Err(ProcessError::NoSuchProcess(self.pid)),
+                Some(libc::EPERM) => Err(ProcessError::AccessDenied(self.pid)),
+                _ => Err(Error::from(e).with_ffi("pidfd_send_signal").into()),
+            }
+        }
+    }
+
+    pub fn signal(&self, signal: Signal) -> ProcessResult<()> {
+        self.send(signal.into())
+    }
+
+    /// Checks if the process was not reaped yet.
+    pub fn is_running(&self) -> ProcessResult<bool> {
+        // Same to `kill(2)`, no signal is sent, but error checking is still performed
+        match self.send(0) {
+            Ok(()) | Err(ProcessError::AccessDenied(..)) => Ok(true),
+            Err(ProcessError::NoSuchProcess(..)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Checks if the process had exited, as pidfd becomes readable at that moment.
+    pub fn has_exited(&self) -> ProcessResult<bool> {
+        let mut pollfd = libc::pollfd {
+            fd: self.fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+
+        let result = unsafe { libc::poll(&mut pollfd, 1, 0) };
+        if result < 0 {
+            let e = io::Error::last_os_error();
+            match e.raw_os_error() {
+                Some(libc::EINTR) => Ok(false),
+                _ => Err(Error::from(e).with_ffi("poll").into()),
+            }
+        } else {
+            Ok(result > 0)
+        }
+    }
+}
+
+impl Drop for PidFd {
+    fn drop(&mut self) {
+        let _ = unsafe { libc::close(self.fd) };
+    }
+}
@@ -145,7 +145,7 @@ impl<'a> Iterator for CommandIter<'a> {
 }
 
 pub async fn command(pid: Pid) -> ProcessResult<Command> {
-    match rt::fs::read_to_string(format!("/proc/{}/cmdline", pid)).await {
+    match rt::fs::read_to_string(rt::fs::proc_path(format!("{}/cmdline", pid))).await {
         Ok(contents) => Ok(Command::from(contents)),
         Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ProcessError::NoSuchProcess(pid)),
         Err(e) => Err(e.into()),
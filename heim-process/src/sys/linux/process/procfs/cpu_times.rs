@@ -20,8 +20,8 @@ impl CpuTime {
     }
 }
 
-impl From<Stat> for CpuTime {
-    fn from(stat: Stat) -> CpuTime {
+impl From<&Stat> for CpuTime {
+    fn from(stat: &Stat) -> CpuTime {
         CpuTime {
             utime: stat.utime,
             stime: stat.stime,
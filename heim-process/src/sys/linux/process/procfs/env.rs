@@ -7,7 +7,7 @@ use crate::{Pid, ProcessError, ProcessResult};
 pub use crate::sys::unix::{Environment, IntoEnvironmentIter};
 
 pub async fn environment(pid: Pid) -> ProcessResult<Environment> {
-    let path = format!("/proc/{}/environ", pid);
+    let path = rt::fs::proc_path(format!("{}/environ", pid));
 
     match rt::fs::read(path).await {
         Ok(contents) => Ok(Environment::from_bytes(&contents)),
@@ -0,0 +1,200 @@
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use heim_common::prelude::*;
+use heim_common::utils::iter::TryIterator;
+use heim_runtime as rt;
+
+use crate::{Pid, ProcessError, ProcessResult};
+
+#[derive(Debug)]
+pub struct OpenFile {
+    fd: i32,
+    path: PathBuf,
+    position: u64,
+    flags: u32,
+}
+
+impl OpenFile {
+    pub fn fd(&self) -> i32 {
+        self.fd
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+}
+
+/// Parsed `/proc/<pid>/fdinfo/<fd>` file, see `proc(5)` for details.
+#[derive(Debug, Default)]
+struct FdInfo {
+    pos: u64,
+    flags: u32,
+}
+
+impl FromStr for FdInfo {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut info = FdInfo::default();
+        for line in s.lines() {
+            let mut parts = line.split_ascii_whitespace();
+            match parts.next() {
+                Some("pos:") => info.pos = parts.try_next()?.parse()?,
+                // Flags are printed in the octal form
+                Some("flags:") => info.flags = u32::from_str_radix(parts.try_next()?, 8)?,
+                _ => continue,
+            }
+        }
+
+        Ok(info)
+    }
+}
+
+/// Returns stream over the paths of the `/proc/<pid>/fd/*` entries.
+async fn fd_entries(pid: Pid) -> ProcessResult<impl Stream<Item = ProcessResult<PathBuf>>> {
+    match rt::fs::read_dir(rt::fs::proc_path(format!("{}/fd", pid))).await {
+        Ok(entries) => Ok(entries.map_ok(|entry| entry.path()).map_err(Into::into)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ProcessError::NoSuchProcess(pid)),
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+            Err(ProcessError::AccessDenied(pid))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Parses `"socket:[12345]"`-like file descriptor link target into the socket inode.
+fn socket_inode(link: &Path) -> Option<u64> {
+    let link = link.to_str()?;
+    if link.starts_with("socket:[") && link.ends_with(']') {
+        link[8..link.len() - 1].parse::<u64>().ok()
+    } else {
+        None
+    }
+}
+
+/// Returns inodes of all sockets opened by process.
+pub async fn socket_inodes(pid: Pid) -> ProcessResult<HashSet<u64>> {
+    let entries = fd_entries(pid).await?;
+    rt::pin!(entries);
+
+    let mut inodes = HashSet::new();
+    while let Some(entry) = entries.next().await {
+        // File descriptor might be already closed at this point
+        let link = match rt::fs::read_link(entry?).await {
+            Ok(link) => link,
+            Err(..) => continue,
+        };
+
+        if let Some(inode) = socket_inode(&link) {
+            let _ = inodes.insert(inode);
+        }
+    }
+
+    Ok(inodes)
+}
+
+/// Checks if `"/memfd:name (deleted)"`-like link target is pointing to the memfd file.
+fn is_memfd(link: &Path) -> bool {
+    link.to_str()
+        .map(|link| link.starts_with("/memfd:"))
+        .unwrap_or(false)
+}
+
+pub async fn num_fds(pid: Pid) -> ProcessResult<usize> {
+    fd_entries(pid)
+        .await?
+        .try_fold(0, |acc, _| future::ok(acc + 1))
+        .await
+}
+
+async fn open_file(pid: Pid, entry: PathBuf) -> ProcessResult<Option<OpenFile>> {
+    let fd = match entry
+        .file_name()
+        .and_then(OsStr::to_str)
+        .and_then(|name| name.parse::<i32>().ok())
+    {
+        Some(fd) => fd,
+        None => return Ok(None),
+    };
+
+    // File descriptor might be already closed at this point
+    let path = match rt::fs::read_link(&entry).await {
+        Ok(path) => path,
+        Err(..) => return Ok(None),
+    };
+
+    // Sockets, pipes and anonymous inodes are represented as `type:[inode]`
+    if !path.is_absolute() {
+        return Ok(None);
+    }
+
+    // Metadata is fetched via the fd link itself, which allows to skip
+    // character devices, deleted memfd files and other non-regular files
+    match rt::fs::metadata(&entry).await {
+        Ok(metadata) if metadata.is_file() && !is_memfd(&path) => {}
+        _ => return Ok(None),
+    }
+
+    let info =
+        match rt::fs::read_to_string(rt::fs::proc_path(format!("{}/fdinfo/{}", pid, fd))).await {
+            Ok(contents) => FdInfo::from_str(&contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+    Ok(Some(OpenFile {
+        fd,
+        path,
+        position: info.pos,
+        flags: info.flags,
+    }))
+}
+
+pub fn open_files(pid: Pid) -> impl Stream<Item = ProcessResult<OpenFile>> {
+    fd_entries(pid)
+        .try_flatten_stream()
+        .try_filter_map(move |entry| open_file(pid, entry))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use super::{is_memfd, socket_inode, FdInfo};
+
+    #[test]
+    fn test_socket_inode() {
+        assert_eq!(socket_inode(Path::new("socket:[21416]")), Some(21416));
+        assert_eq!(socket_inode(Path::new("pipe:[21416]")), None);
+        assert_eq!(socket_inode(Path::new("/dev/null")), None);
+    }
+
+    #[test]
+    fn test_is_memfd() {
+        assert!(is_memfd(Path::new("/memfd:wayland-shm (deleted)")));
+        assert!(!is_memfd(Path::new("/home/user/memfd:notes.txt")));
+        assert!(!is_memfd(Path::new("/dev/null")));
+    }
+
+    #[test]
+    fn test_fdinfo() {
+        let contents = "pos:\t4096\nflags:\t0100002\nmnt_id:\t29\nino:\t1835011\n";
+        let info = FdInfo::from_str(contents).unwrap();
+
+        assert_eq!(info.pos, 4096);
+        assert_eq!(info.flags, 0o100_002);
+    }
+}
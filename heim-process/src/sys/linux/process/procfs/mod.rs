@@ -1,13 +1,21 @@
 mod command;
 mod cpu_times;
 mod env;
+mod fd;
 mod io;
+mod smaps;
 mod stat;
 mod statm;
+mod status;
+mod task;
 
 pub use self::command::{command, Command, CommandIter};
 pub use self::cpu_times::CpuTime;
 pub use self::env::{environment, Environment, IntoEnvironmentIter};
+pub use self::fd::{num_fds, open_files, socket_inodes, OpenFile};
 pub use self::io::io;
+pub use self::smaps::{full_memory, memory_maps};
 pub use self::stat::{stat, Stat};
 pub use self::statm::{stat_memory, Memory};
+pub use self::status::{status, ProcStatus};
+pub use self::task::{threads, Thread};
@@ -0,0 +1,174 @@
+use std::io;
+use std::path::PathBuf;
+
+use heim_common::prelude::*;
+use heim_common::units::{information, Information};
+use heim_common::utils::iter::{ParseIterator, TryIterator};
+use heim_runtime as rt;
+
+use crate::os::linux::{FullMemory, MemoryMap};
+use crate::sys::pid_exists;
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Parses `"00400000-0040b000 r-xp 00000000 08:01 1048602 /bin/cat"`-like region header.
+fn parse_header(line: &str) -> Result<MemoryMap> {
+    let mut parts = line.split_ascii_whitespace();
+    let mut address = parts.try_next()?.splitn(2, '-');
+    let start = u64::from_str_radix(address.try_next()?, 16)?;
+    let end = u64::from_str_radix(address.try_next()?, 16)?;
+    let permissions = parts.try_next()?.to_string();
+    let offset = u64::from_str_radix(parts.try_next()?, 16)?;
+    let _device = parts.try_next()?;
+    let _inode: u64 = parts.try_parse_next()?;
+    // Path might contain spaces
+    let path = parts.collect::<Vec<_>>().join(" ");
+
+    Ok(MemoryMap {
+        address: start..end,
+        permissions,
+        offset,
+        path: if path.is_empty() {
+            None
+        } else {
+            Some(PathBuf::from(path))
+        },
+        ..Default::default()
+    })
+}
+
+/// Parses `/proc/<pid>/smaps` or `/proc/<pid>/smaps_rollup` file contents.
+///
+/// Rollup file is formatted in the same way, but contains one region only.
+fn parse_smaps(contents: &str) -> Result<Vec<MemoryMap>> {
+    let mut maps = Vec::new();
+
+    for line in contents.lines() {
+        let mut parts = line.split_ascii_whitespace();
+        let key = match parts.next() {
+            Some(key) if key.ends_with(':') => key,
+            Some(..) => {
+                maps.push(parse_header(line)?);
+                continue;
+            }
+            None => continue,
+        };
+        let map = match maps.last_mut() {
+            Some(map) => map,
+            None => continue,
+        };
+
+        let field = match key {
+            "Size:" => &mut map.size,
+            "Rss:" => &mut map.rss,
+            "Pss:" => &mut map.pss,
+            "Shared_Clean:" => &mut map.shared_clean,
+            "Shared_Dirty:" => &mut map.shared_dirty,
+            "Private_Clean:" => &mut map.private_clean,
+            "Private_Dirty:" => &mut map.private_dirty,
+            "Referenced:" => &mut map.referenced,
+            "Anonymous:" => &mut map.anonymous,
+            "Swap:" => &mut map.swap,
+            _ => continue,
+        };
+
+        *field = Information::new::<information::kibibyte>(parts.try_parse_next()?);
+    }
+
+    Ok(maps)
+}
+
+async fn read_smaps(pid: Pid, file: &str) -> ProcessResult<Vec<MemoryMap>> {
+    let path = rt::fs::proc_path(format!("{}/{}", pid, file));
+    match rt::fs::read_to_string(&path).await {
+        Ok(contents) => parse_smaps(&contents).map_err(|e| e.with_file(path).into()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ProcessError::NoSuchProcess(pid)),
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+            Err(ProcessError::AccessDenied(pid))
+        }
+        Err(e) => Err(Error::from(e).with_file(path).into()),
+    }
+}
+
+pub fn memory_maps(pid: Pid) -> impl Stream<Item = ProcessResult<MemoryMap>> {
+    read_smaps(pid, "smaps")
+        .map_ok(|maps| stream::iter(maps).map(Ok))
+        .try_flatten_stream()
+}
+
+pub async fn full_memory(pid: Pid) -> ProcessResult<FullMemory> {
+    // `smaps_rollup` is available since Linux 4.14 and it is way faster
+    // than summing all the `smaps` entries, but it should be checked first
+    // if the process exists at all, as both missing file and missing process
+    // are reported via `NotFound` error.
+    let maps = match read_smaps(pid, "smaps_rollup").await {
+        Ok(maps) => maps,
+        Err(ProcessError::NoSuchProcess(..)) if pid_exists(pid).await? => {
+            read_smaps(pid, "smaps").await?
+        }
+        Err(e) => return Err(e),
+    };
+
+    Ok(maps
+        .into_iter()
+        .fold(FullMemory::default(), |mut acc, map| {
+            acc.uss += map.private_clean + map.private_dirty;
+            acc.pss += map.pss;
+            acc.swap += map.swap;
+
+            acc
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use heim_common::units::information;
+
+    use super::parse_smaps;
+
+    #[test]
+    fn test_parse_smaps() {
+        let contents = "\
+00400000-0040c000 r-xp 00000000 08:01 1048602                            /bin/cat
+Size:                 48 kB
+KernelPageSize:        4 kB
+Rss:                  44 kB
+Pss:                  22 kB
+Shared_Clean:         40 kB
+Shared_Dirty:          0 kB
+Private_Clean:         4 kB
+Private_Dirty:         0 kB
+Referenced:           44 kB
+Anonymous:             0 kB
+Swap:                  0 kB
+VmFlags: rd ex mr mw me dw sd
+7ffc2c5d4000-7ffc2c5f5000 rw-p 00000000 00:00 0                          [stack]
+Size:                132 kB
+Rss:                  12 kB
+Pss:                  12 kB
+Private_Dirty:        12 kB
+Anonymous:            12 kB
+Swap:                  8 kB
+7f1b4c000000-7f1b4c021000 rw-p 00000000 00:00 0
+Size:                132 kB
+Rss:                   4 kB
+";
+        let maps = parse_smaps(contents).unwrap();
+        assert_eq!(maps.len(), 3);
+
+        assert_eq!(maps[0].address(), 0x0040_0000..0x0040_c000);
+        assert_eq!(maps[0].permissions(), "r-xp");
+        assert_eq!(maps[0].path(), Some(Path::new("/bin/cat")));
+        assert_eq!(maps[0].size().get::<information::kibibyte>(), 48);
+        assert_eq!(maps[0].pss().get::<information::kibibyte>(), 22);
+        assert_eq!(maps[0].private_clean().get::<information::kibibyte>(), 4);
+
+        assert_eq!(maps[1].path(), Some(Path::new("[stack]")));
+        assert_eq!(maps[1].private_dirty().get::<information::kibibyte>(), 12);
+        assert_eq!(maps[1].swap().get::<information::kibibyte>(), 8);
+
+        assert_eq!(maps[2].path(), None);
+        assert_eq!(maps[2].rss().get::<information::kibibyte>(), 4);
+    }
+}
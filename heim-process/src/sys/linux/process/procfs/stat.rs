@@ -1,5 +1,6 @@
 use std::convert::TryFrom;
 use std::io;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 use heim_common::prelude::*;
@@ -53,11 +54,23 @@ pub struct Stat {
     pub name: String,
     pub state: Status,
     pub ppid: Pid,
+    pub pgrp: Pid,
+    pub session_id: Pid,
+    pub tty_nr: i32,
+    pub tpgid: Pid,
     pub create_time: Time,
     pub utime: Time,
     pub stime: Time,
     pub cutime: Time,
     pub cstime: Time,
+    pub minflt: u64,
+    pub majflt: u64,
+    pub priority: i32,
+    pub nice: i32,
+    pub num_threads: u64,
+    pub processor: usize,
+    pub rt_priority: u32,
+    pub delayacct_blkio: Time,
 }
 
 impl FromStr for Stat {
@@ -75,27 +88,33 @@ impl FromStr for Stat {
         let mut parts = leftover[comm_end + 2..].split_whitespace();
         let state: Status = parts.try_parse_next()?;
         let ppid: Pid = parts.try_parse_next()?;
-        let _pgrp: i32 = parts.try_parse_next()?;
-        let _session_id: i32 = parts.try_parse_next()?;
-        let _tty_nr: i32 = parts.try_parse_next()?;
-        let _tpgid: i32 = parts.try_parse_next()?;
+        let pgrp: Pid = parts.try_parse_next()?;
+        let session_id: Pid = parts.try_parse_next()?;
+        let tty_nr: i32 = parts.try_parse_next()?;
+        let tpgid: Pid = parts.try_parse_next()?;
         let _flags: u32 = parts.try_parse_next()?;
-        let _minflt: u64 = parts.try_parse_next()?;
+        let minflt: u64 = parts.try_parse_next()?;
         let _cminflt: u64 = parts.try_parse_next()?;
-        let _majflt: u64 = parts.try_parse_next()?;
+        let majflt: u64 = parts.try_parse_next()?;
         let _cmajflt: u64 = parts.try_parse_next()?;
         let utime: u64 = parts.try_parse_next()?;
         let stime: u64 = parts.try_parse_next()?;
         let cutime: i64 = parts.try_parse_next()?;
         let cstime: i64 = parts.try_parse_next()?;
-        let _priority: i64 = parts.try_parse_next()?;
-        let _nice: i64 = parts.try_parse_next()?;
-        let _num_threads: i64 = parts.try_parse_next()?;
+        let priority: i32 = parts.try_parse_next()?;
+        let nice: i32 = parts.try_parse_next()?;
+        let num_threads: u64 = parts.try_parse_next()?;
         let _itrealvalue: i64 = parts.try_parse_next()?;
         let start_time: i64 = parts.try_parse_next()?;
         let _vsize: i64 = parts.try_parse_next()?;
         let _rss: i64 = parts.try_parse_next()?;
         let _rsslim: u64 = parts.try_parse_next()?;
+        // `startcode` .. `exit_signal` fields
+        let mut parts = parts.skip(13);
+        let processor: usize = parts.try_parse_next()?;
+        let rt_priority: u32 = parts.try_parse_next()?;
+        let _policy: u32 = parts.try_parse_next()?;
+        let delayacct_blkio_ticks: u64 = parts.try_parse_next()?;
         // ...
 
         let start_time = start_time as f64 / *CLOCK_TICKS;
@@ -106,18 +125,29 @@ impl FromStr for Stat {
             name,
             state,
             ppid,
+            pgrp,
+            session_id,
+            tty_nr,
+            tpgid,
             create_time: Time::new::<time::second>(start_time),
             // TODO: Possible values truncation during the `as f64` cast
             utime: Time::new::<time::second>(utime as f64 / *CLOCK_TICKS),
             stime: Time::new::<time::second>(stime as f64 / *CLOCK_TICKS),
             cutime: Time::new::<time::second>(cutime as f64 / *CLOCK_TICKS),
             cstime: Time::new::<time::second>(cstime as f64 / *CLOCK_TICKS),
+            minflt,
+            majflt,
+            priority,
+            nice,
+            num_threads,
+            processor,
+            rt_priority,
+            delayacct_blkio: Time::new::<time::second>(delayacct_blkio_ticks as f64 / *CLOCK_TICKS),
         })
     }
 }
 
-pub async fn stat(pid: Pid) -> ProcessResult<Stat> {
-    let path = format!("/proc/{}/stat", pid);
+async fn read_stat(pid: Pid, path: PathBuf) -> ProcessResult<Stat> {
     let contents = match rt::fs::read_to_string(&path).await {
         Ok(contents) => contents,
         Err(e) if e.kind() == io::ErrorKind::NotFound => {
@@ -133,3 +163,56 @@ pub async fn stat(pid: Pid) -> ProcessResult<Stat> {
 
     Ok(stats)
 }
+
+pub async fn stat(pid: Pid) -> ProcessResult<Stat> {
+    read_stat(pid, rt::fs::proc_path(format!("{}/stat", pid))).await
+}
+
+/// Loads `/proc/<pid>/task/<tid>/stat` file for the process thread.
+///
+/// Returned `Stat::pid` field contains the thread id.
+pub async fn task_stat(pid: Pid, tid: Pid) -> ProcessResult<Stat> {
+    read_stat(pid, rt::fs::proc_path(format!("{}/task/{}/stat", pid, tid))).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use heim_common::sys::unix::CLOCK_TICKS;
+    use heim_common::units::{time, Time};
+
+    use super::Stat;
+    use crate::Status;
+
+    #[test]
+    fn test_parse() {
+        let contents =
+            "1234 (tmux: server) S 1 1234 1234 34816 1234 4194560 5311 0 12 0 77 31 0 0 \
+                        20 0 1 0 381141 2703360 285 18446744073709551615 94594724704256 \
+                        94594724724137 140729851119632 0 0 0 0 4096 134433283 0 0 0 17 3 0 0 42 \
+                        0 0 94594724740144 94594724741760 94594780450816 140729851127192 \
+                        140729851127212 140729851127212 140729851129835 0\n";
+        let stat = Stat::from_str(contents).unwrap();
+
+        assert_eq!(stat.pid, 1234);
+        assert_eq!(stat.name, "tmux: server");
+        assert_eq!(stat.state, Status::Sleeping);
+        assert_eq!(stat.ppid, 1);
+        assert_eq!(stat.pgrp, 1234);
+        assert_eq!(stat.session_id, 1234);
+        assert_eq!(stat.tty_nr, 34816);
+        assert_eq!(stat.tpgid, 1234);
+        assert_eq!(stat.minflt, 5311);
+        assert_eq!(stat.majflt, 12);
+        assert_eq!(stat.priority, 20);
+        assert_eq!(stat.nice, 0);
+        assert_eq!(stat.num_threads, 1);
+        assert_eq!(stat.processor, 3);
+        assert_eq!(stat.rt_priority, 0);
+        assert_eq!(
+            stat.delayacct_blkio,
+            Time::new::<time::second>(42.0 / *CLOCK_TICKS)
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use std::io;
+use std::str::FromStr;
+
+use heim_common::prelude::*;
+use heim_common::utils::iter::{ParseIterator, TryIterator};
+use heim_runtime as rt;
+
+use crate::os::unix::{Gids, Uids};
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Parsed `/proc/<pid>/status` file, see `proc(5)` for details.
+#[derive(Debug)]
+pub struct ProcStatus {
+    pub uids: Uids,
+    pub gids: Gids,
+    pub groups: Vec<u32>,
+    /// Context switches counters are available since Linux 2.6.23
+    pub voluntary_ctxt_switches: Option<u64>,
+    pub nonvoluntary_ctxt_switches: Option<u64>,
+}
+
+/// Parses `"1000\t1000\t1000\t1000"`-like value into the real, effective and saved IDs.
+fn parse_ids(value: &str) -> Result<(u32, u32, u32)> {
+    let mut parts = value.split_ascii_whitespace();
+
+    Ok((
+        parts.try_parse_next()?,
+        parts.try_parse_next()?,
+        parts.try_parse_next()?,
+    ))
+}
+
+impl FromStr for ProcStatus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut uids = None;
+        let mut gids = None;
+        let mut groups = Vec::new();
+        let mut voluntary_ctxt_switches = None;
+        let mut nonvoluntary_ctxt_switches = None;
+
+        for line in s.lines() {
+            let mut parts = line.splitn(2, ':');
+            let key = parts.try_next()?;
+            let value = match parts.next() {
+                Some(value) => value,
+                None => continue,
+            };
+
+            match key {
+                "Uid" => {
+                    let (real, effective, saved) = parse_ids(value)?;
+                    uids = Some(Uids {
+                        real,
+                        effective,
+                        saved,
+                    });
+                }
+                "Gid" => {
+                    let (real, effective, saved) = parse_ids(value)?;
+                    gids = Some(Gids {
+                        real,
+                        effective,
+                        saved,
+                    });
+                }
+                "Groups" => {
+                    for group in value.split_ascii_whitespace() {
+                        groups.push(group.parse()?);
+                    }
+                }
+                "voluntary_ctxt_switches" => {
+                    voluntary_ctxt_switches = Some(value.trim().parse()?);
+                }
+                "nonvoluntary_ctxt_switches" => {
+                    nonvoluntary_ctxt_switches = Some(value.trim().parse()?);
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(ProcStatus {
+            uids: uids.ok_or_else(|| Error::missing_key("Uid", "/proc/<pid>/status"))?,
+            gids: gids.ok_or_else(|| Error::missing_key("Gid", "/proc/<pid>/status"))?,
+            groups,
+            voluntary_ctxt_switches,
+            nonvoluntary_ctxt_switches,
+        })
+    }
+}
+
+pub async fn status(pid: Pid) -> ProcessResult<ProcStatus> {
+    let path = rt::fs::proc_path(format!("{}/status", pid));
+    match rt::fs::read_to_string(&path).await {
+        Ok(contents) => ProcStatus::from_str(&contents).map_err(Into::into),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ProcessError::NoSuchProcess(pid)),
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+            Err(ProcessError::AccessDenied(pid))
+        }
+        Err(e) => Err(Error::from(e).with_file(path).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::ProcStatus;
+
+    #[test]
+    fn test_parse() {
+        let contents = "Name:\tbash
+Umask:\t0022
+State:\tS (sleeping)
+Tgid:\t4075
+Pid:\t4075
+PPid:\t4067
+Uid:\t1000\t1001\t1002\t1000
+Gid:\t100\t101\t102\t100
+FDSize:\t256
+Groups:\t4 24 27 100 
+Threads:\t1
+voluntary_ctxt_switches:\t150
+nonvoluntary_ctxt_switches:\t545
+";
+        let status = ProcStatus::from_str(contents).unwrap();
+
+        assert_eq!(status.uids.real(), 1000);
+        assert_eq!(status.uids.effective(), 1001);
+        assert_eq!(status.uids.saved(), 1002);
+        assert_eq!(status.gids.real(), 100);
+        assert_eq!(status.gids.effective(), 101);
+        assert_eq!(status.gids.saved(), 102);
+        assert_eq!(status.groups, vec![4, 24, 27, 100]);
+        assert_eq!(status.voluntary_ctxt_switches, Some(150));
+        assert_eq!(status.nonvoluntary_ctxt_switches, Some(545));
+    }
+}
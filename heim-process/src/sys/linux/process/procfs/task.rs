@@ -0,0 +1,74 @@
+use std::io;
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use super::stat::task_stat;
+use crate::{CpuTime, Pid, ProcessError, ProcessResult, Status};
+
+#[derive(Debug)]
+pub struct Thread {
+    id: Pid,
+    name: String,
+    status: Status,
+    cpu_time: CpuTime,
+}
+
+impl Thread {
+    pub fn id(&self) -> Pid {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn cpu_time(&self) -> &CpuTime {
+        &self.cpu_time
+    }
+}
+
+async fn thread(pid: Pid, tid: Pid) -> ProcessResult<Option<Thread>> {
+    match task_stat(pid, tid).await {
+        Ok(stat) => {
+            let id = stat.pid;
+            let name = stat.name.clone();
+            let status = stat.state;
+
+            Ok(Some(Thread {
+                id,
+                name,
+                status,
+                cpu_time: CpuTime::from(super::CpuTime::from(&stat)),
+            }))
+        }
+        // Thread might exit while we were iterating over the tasks
+        Err(ProcessError::NoSuchProcess(..)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn threads(pid: Pid) -> impl Stream<Item = ProcessResult<Thread>> {
+    rt::fs::read_dir(rt::fs::proc_path(format!("{}/task", pid)))
+        .map_err(move |e| match e.kind() {
+            io::ErrorKind::NotFound => ProcessError::NoSuchProcess(pid),
+            io::ErrorKind::PermissionDenied => ProcessError::AccessDenied(pid),
+            _ => e.into(),
+        })
+        .map_ok(|entries| entries.map_err(ProcessError::from))
+        .try_flatten_stream()
+        .try_filter_map(move |entry| {
+            match entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<Pid>().ok())
+            {
+                Some(tid) => thread(pid, tid).left_future(),
+                None => future::ok(None).right_future(),
+            }
+        })
+}
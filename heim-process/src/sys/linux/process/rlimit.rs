@@ -0,0 +1,174 @@
+use std::io;
+use std::ptr;
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use crate::os::linux::{Resource, Rlimit};
+use crate::{Pid, ProcessError, ProcessResult};
+
+#[cfg(target_env = "musl")]
+type RlimitResource = libc::c_int;
+#[cfg(not(target_env = "musl"))]
+type RlimitResource = libc::__rlimit_resource_t;
+
+fn resource_id(resource: Resource) -> RlimitResource {
+    match resource {
+        Resource::AddressSpace => libc::RLIMIT_AS,
+        Resource::Core => libc::RLIMIT_CORE,
+        Resource::Cpu => libc::RLIMIT_CPU,
+        Resource::Data => libc::RLIMIT_DATA,
+        Resource::FileSize => libc::RLIMIT_FSIZE,
+        Resource::Locks => libc::RLIMIT_LOCKS,
+        Resource::MemLock => libc::RLIMIT_MEMLOCK,
+        Resource::MsgQueue => libc::RLIMIT_MSGQUEUE,
+        Resource::Nice => libc::RLIMIT_NICE,
+        Resource::NoFile => libc::RLIMIT_NOFILE,
+        Resource::NProc => libc::RLIMIT_NPROC,
+        Resource::Rss => libc::RLIMIT_RSS,
+        Resource::RtPrio => libc::RLIMIT_RTPRIO,
+        Resource::RtTime => libc::RLIMIT_RTTIME,
+        Resource::SigPending => libc::RLIMIT_SIGPENDING,
+        Resource::Stack => libc::RLIMIT_STACK,
+    }
+}
+
+fn from_raw(value: libc::rlim_t) -> Option<u64> {
+    if value == libc::RLIM_INFINITY {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn into_raw(value: Option<u64>) -> libc::rlim_t {
+    value.unwrap_or(libc::RLIM_INFINITY)
+}
+
+/// Calls `prlimit(2)`, returning the previous limits for `resource`.
+fn prlimit(pid: Pid, resource: Resource, new: Option<Rlimit>) -> io::Result<Rlimit> {
+    let new = new.map(|limit| libc::rlimit {
+        rlim_cur: into_raw(limit.soft),
+        rlim_max: into_raw(limit.hard),
+    });
+    let mut old = libc::rlimit {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+
+    let new_ptr: *const libc::rlimit = match new.as_ref() {
+        Some(new) => new,
+        None => ptr::null(),
+    };
+
+    let result = unsafe { libc::prlimit(pid, resource_id(resource), new_ptr, &mut old) };
+
+    if result < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(Rlimit {
+            soft: from_raw(old.rlim_cur),
+            hard: from_raw(old.rlim_max),
+        })
+    }
+}
+
+fn parse_value(value: &str) -> Result<Option<u64>> {
+    match value {
+        "unlimited" => Ok(None),
+        value => Ok(Some(value.parse()?)),
+    }
+}
+
+/// Parses `/proc/<pid>/limits` file contents, see `proc(5)` for details.
+fn parse_limits(contents: &str, resource: Resource) -> Result<Rlimit> {
+    let name = resource.proc_name();
+
+    for line in contents.lines() {
+        // None of the resource names is a prefix of another one
+        if !line.starts_with(name) {
+            continue;
+        }
+
+        let mut parts = line[name.len()..].split_ascii_whitespace();
+        let soft = parts.next().map(parse_value);
+        let hard = parts.next().map(parse_value);
+
+        if let (Some(soft), Some(hard)) = (soft, hard) {
+            return Ok(Rlimit {
+                soft: soft?,
+                hard: hard?,
+            });
+        }
+    }
+
+    Err(Error::missing_key(name, "/proc/<pid>/limits"))
+}
+
+async fn proc_limits(pid: Pid, resource: Resource) -> ProcessResult<Rlimit> {
+    let path = rt::fs::proc_path(format!("{}/limits", pid));
+    match rt::fs::read_to_string(&path).await {
+        Ok(contents) => parse_limits(&contents, resource).map_err(Into::into),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ProcessError::NoSuchProcess(pid)),
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+            Err(ProcessError::AccessDenied(pid))
+        }
+        Err(e) => Err(Error::from(e).with_file(path).into()),
+    }
+}
+
+pub async fn rlimit(pid: Pid, resource: Resource) -> ProcessResult<Rlimit> {
+    match prlimit(pid, resource, None) {
+        Ok(limit) => Ok(limit),
+        Err(e) => match e.raw_os_error() {
+            Some(libc::ESRCH) => Err(ProcessError::NoSuchProcess(pid)),
+            // Reading limits of the other users' processes requires `CAP_SYS_RESOURCE`,
+            // while `/proc/<pid>/limits` is readable by anyone.
+            // `ENOSYS` is returned by kernels older than 2.6.36.
+            Some(libc::EPERM) | Some(libc::ENOSYS) => proc_limits(pid, resource).await,
+            _ => Err(Error::from(e).with_ffi("prlimit").into()),
+        },
+    }
+}
+
+pub fn set_rlimit(pid: Pid, resource: Resource, limit: Rlimit) -> ProcessResult<()> {
+    match prlimit(pid, resource, Some(limit)) {
+        Ok(..) => Ok(()),
+        Err(e) => match e.raw_os_error() {
+            Some(libc::ESRCH) => Err(ProcessError::NoSuchProcess(pid)),
+            Some(libc::EPERM) => Err(ProcessError::AccessDenied(pid)),
+            _ => Err(Error::from(e).with_ffi("prlimit").into()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_limits;
+    use crate::os::linux::Resource;
+
+    static LIMITS: &str = "\
+Limit                     Soft Limit           Hard Limit           Units
+Max cpu time              unlimited            unlimited            seconds
+Max stack size            8388608              unlimited            bytes
+Max processes             23960                23960                processes
+Max open files            1024                 1048576              files
+Max nice priority         0                    0
+";
+
+    #[test]
+    fn test_parse_limits() {
+        let limit = parse_limits(LIMITS, Resource::NoFile).unwrap();
+        assert_eq!(limit.soft(), Some(1024));
+        assert_eq!(limit.hard(), Some(1_048_576));
+
+        let limit = parse_limits(LIMITS, Resource::Stack).unwrap();
+        assert_eq!(limit.soft(), Some(8_388_608));
+        assert_eq!(limit.hard(), None);
+
+        let limit = parse_limits(LIMITS, Resource::Nice).unwrap();
+        assert_eq!(limit.soft(), Some(0));
+
+        assert!(parse_limits(LIMITS, Resource::RtTime).is_err());
+    }
+}
@@ -0,0 +1,192 @@
+use std::convert::TryFrom;
+use std::io;
+use std::mem;
+
+use heim_common::{Error, Result};
+
+use crate::os::linux::{IoPriority, SchedPolicy};
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Converts last `sched_*` syscall error into the process error.
+fn last_error(pid: Pid, func: &'static str) -> ProcessError {
+    let e = io::Error::last_os_error();
+    match e.raw_os_error() {
+        Some(libc::ESRCH) => ProcessError::NoSuchProcess(pid),
+        Some(libc::EPERM) => ProcessError::AccessDenied(pid),
+        _ => Error::from(e).with_ffi(func).into(),
+    }
+}
+
+pub fn cpu_affinity(pid: Pid) -> ProcessResult<Vec<usize>> {
+    let mut set = unsafe { mem::zeroed::<libc::cpu_set_t>() };
+    let result =
+        unsafe { libc::sched_getaffinity(pid, mem::size_of::<libc::cpu_set_t>(), &mut set) };
+
+    if result < 0 {
+        return Err(last_error(pid, "sched_getaffinity"));
+    }
+
+    Ok((0..libc::CPU_SETSIZE as usize)
+        .filter(|cpu| unsafe { libc::CPU_ISSET(*cpu, &set) })
+        .collect())
+}
+
+pub fn set_cpu_affinity(pid: Pid, cpus: &[usize]) -> ProcessResult<()> {
+    let mut set = unsafe { mem::zeroed::<libc::cpu_set_t>() };
+    for cpu in cpus {
+        if *cpu >= libc::CPU_SETSIZE as usize {
+            let inner = io::Error::from(io::ErrorKind::InvalidInput);
+            return Err(Error::from(inner)
+                .with_message(format!("CPU index {} is out of range", cpu))
+                .into());
+        }
+        unsafe { libc::CPU_SET(*cpu, &mut set) };
+    }
+
+    let result = unsafe { libc::sched_setaffinity(pid, mem::size_of::<libc::cpu_set_t>(), &set) };
+
+    if result < 0 {
+        Err(last_error(pid, "sched_setaffinity"))
+    } else {
+        Ok(())
+    }
+}
+
+pub fn sched_policy(pid: Pid) -> ProcessResult<SchedPolicy> {
+    let result = unsafe { libc::sched_getscheduler(pid) };
+
+    if result < 0 {
+        Err(last_error(pid, "sched_getscheduler"))
+    } else {
+        SchedPolicy::try_from(result).map_err(|e| {
+            Error::from(e)
+                .with_message(format!("Unknown scheduling policy {}", result))
+                .into()
+        })
+    }
+}
+
+pub fn sched_priority(pid: Pid) -> ProcessResult<i32> {
+    let mut param = unsafe { mem::zeroed::<libc::sched_param>() };
+    let result = unsafe { libc::sched_getparam(pid, &mut param) };
+
+    if result < 0 {
+        Err(last_error(pid, "sched_getparam"))
+    } else {
+        Ok(param.sched_priority)
+    }
+}
+
+pub fn set_sched_policy(pid: Pid, policy: SchedPolicy, priority: i32) -> ProcessResult<()> {
+    // `SCHED_DEADLINE` requires the `sched_setattr` syscall with the runtime parameters
+    if policy == SchedPolicy::Deadline {
+        let inner = io::Error::from(io::ErrorKind::InvalidInput);
+        return Err(Error::from(inner)
+            .with_message("SCHED_DEADLINE policy can't be set with this method")
+            .into());
+    }
+
+    let param = libc::sched_param {
+        sched_priority: priority,
+    };
+    let result = unsafe { libc::sched_setscheduler(pid, policy.into(), &param) };
+
+    if result < 0 {
+        Err(last_error(pid, "sched_setscheduler"))
+    } else {
+        Ok(())
+    }
+}
+
+// Declared at `include/uapi/linux/ioprio.h`
+const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+const IOPRIO_CLASS_SHIFT: libc::c_int = 13;
+const IOPRIO_PRIO_MASK: libc::c_int = (1 << IOPRIO_CLASS_SHIFT) - 1;
+const IOPRIO_CLASS_NONE: libc::c_int = 0;
+const IOPRIO_CLASS_RT: libc::c_int = 1;
+const IOPRIO_CLASS_BE: libc::c_int = 2;
+const IOPRIO_CLASS_IDLE: libc::c_int = 3;
+
+fn parse_io_priority(value: libc::c_int) -> Result<IoPriority> {
+    // Level is always in the `0..=7` range, so it is safe to truncate it
+    let level = (value & IOPRIO_PRIO_MASK) as u8;
+
+    match value >> IOPRIO_CLASS_SHIFT {
+        IOPRIO_CLASS_NONE => Ok(IoPriority::None),
+        IOPRIO_CLASS_RT => Ok(IoPriority::RealTime(level)),
+        IOPRIO_CLASS_BE => Ok(IoPriority::BestEffort(level)),
+        IOPRIO_CLASS_IDLE => Ok(IoPriority::Idle),
+        other => {
+            let inner = io::Error::from(io::ErrorKind::InvalidData);
+
+            Err(Error::from(inner).with_message(format!("Unknown I/O priority class {}", other)))
+        }
+    }
+}
+
+fn format_io_priority(priority: IoPriority) -> Result<libc::c_int> {
+    let (class, level) = match priority {
+        IoPriority::None => (IOPRIO_CLASS_NONE, 0),
+        IoPriority::RealTime(level) => (IOPRIO_CLASS_RT, level),
+        IoPriority::BestEffort(level) => (IOPRIO_CLASS_BE, level),
+        IoPriority::Idle => (IOPRIO_CLASS_IDLE, 0),
+    };
+
+    if level > 7 {
+        let inner = io::Error::from(io::ErrorKind::InvalidInput);
+
+        return Err(Error::from(inner).with_message(format!(
+            "I/O priority level {} is out of the 0..=7 range",
+            level
+        )));
+    }
+
+    Ok(class << IOPRIO_CLASS_SHIFT | libc::c_int::from(level))
+}
+
+pub fn io_priority(pid: Pid) -> ProcessResult<IoPriority> {
+    let result = unsafe { libc::syscall(libc::SYS_ioprio_get, IOPRIO_WHO_PROCESS, pid) };
+
+    if result < 0 {
+        Err(last_error(pid, "ioprio_get"))
+    } else {
+        parse_io_priority(result as libc::c_int).map_err(Into::into)
+    }
+}
+
+pub fn set_io_priority(pid: Pid, priority: IoPriority) -> ProcessResult<()> {
+    let value = format_io_priority(priority)?;
+    let result = unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, pid, value) };
+
+    if result < 0 {
+        Err(last_error(pid, "ioprio_set"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_io_priority, parse_io_priority};
+    use crate::os::linux::IoPriority;
+
+    #[test]
+    fn test_io_priority() {
+        for priority in &[
+            IoPriority::None,
+            IoPriority::RealTime(0),
+            IoPriority::BestEffort(4),
+            IoPriority::BestEffort(7),
+            IoPriority::Idle,
+        ] {
+            let value = format_io_priority(*priority).unwrap();
+            assert_eq!(parse_io_priority(value).unwrap(), *priority);
+        }
+
+        assert_eq!(
+            format_io_priority(IoPriority::BestEffort(4)).unwrap(),
+            0x4004
+        );
+        assert!(format_io_priority(IoPriority::RealTime(8)).is_err());
+    }
+}
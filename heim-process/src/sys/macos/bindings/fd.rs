@@ -0,0 +1,148 @@
+use std::mem;
+use std::ptr;
+
+use darwin_libproc::proc_fdinfo;
+
+use heim_common::{Error, Result};
+
+use crate::Pid;
+
+// Declared at `bsd/sys/proc_info.h`
+
+/// `proc_pidinfo` flavor to list process file descriptors.
+pub const PROC_PIDLISTFDS: libc::c_int = 1;
+/// `proc_pidfdinfo` flavor to get vnode information along with its path.
+pub const PROC_PIDFDVNODEPATHINFO: libc::c_int = 2;
+/// File descriptor type for the vnode-backed files.
+pub const PROX_FDTYPE_VNODE: u32 = 1;
+
+// Declared at `bsd/sys/param.h`
+const MAXPATHLEN: usize = libc::PATH_MAX as usize;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct proc_fileinfo {
+    pub fi_openflags: u32,
+    pub fi_status: u32,
+    pub fi_offset: libc::off_t,
+    pub fi_type: i32,
+    pub fi_guardflags: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct vnode_info_path {
+    // TODO: It should be a `struct vnode_info`,
+    // but since only the `vst_mode` field is used and it's declaration kinda big,
+    // it is declared as an opaque blob of the same size and alignment.
+    pub vip_vi: [u64; 19],
+    pub vip_path: [libc::c_char; MAXPATHLEN],
+}
+
+impl vnode_info_path {
+    /// Returns `vi_stat.vst_mode` field value.
+    ///
+    /// `struct vinfo_stat` starts with the `uint32_t vst_dev` field,
+    /// which is followed by the `uint16_t vst_mode` one.
+    pub fn mode(&self) -> libc::mode_t {
+        let bytes = self.vip_vi[0].to_ne_bytes();
+
+        libc::mode_t::from_ne_bytes([bytes[4], bytes[5]])
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct vnode_fdinfowithpath {
+    pub pfi: proc_fileinfo,
+    pub pvip: vnode_info_path,
+}
+
+extern "C" {
+    fn proc_pidinfo(
+        pid: libc::c_int,
+        flavor: libc::c_int,
+        arg: u64,
+        buffer: *mut libc::c_void,
+        buffersize: libc::c_int,
+    ) -> libc::c_int;
+
+    fn proc_pidfdinfo(
+        pid: libc::c_int,
+        fd: libc::c_int,
+        flavor: libc::c_int,
+        buffer: *mut libc::c_void,
+        buffersize: libc::c_int,
+    ) -> libc::c_int;
+}
+
+/// Returns all file descriptors opened by process.
+pub fn pid_fds(pid: Pid) -> Result<Vec<proc_fdinfo>> {
+    // At first we are requesting the buffer size needed
+    let size = unsafe { proc_pidinfo(pid, PROC_PIDLISTFDS, 0, ptr::null_mut(), 0) };
+    if size <= 0 {
+        return Err(Error::last_os_error().with_ffi("proc_pidinfo"));
+    }
+
+    let capacity = size as usize / mem::size_of::<proc_fdinfo>();
+    let mut fds: Vec<proc_fdinfo> = Vec::with_capacity(capacity);
+
+    let size = unsafe {
+        proc_pidinfo(
+            pid,
+            PROC_PIDLISTFDS,
+            0,
+            fds.as_mut_ptr() as *mut libc::c_void,
+            size,
+        )
+    };
+    if size <= 0 {
+        return Err(Error::last_os_error().with_ffi("proc_pidinfo"));
+    }
+
+    unsafe {
+        fds.set_len(size as usize / mem::size_of::<proc_fdinfo>());
+    }
+
+    Ok(fds)
+}
+
+/// Returns vnode information for the file descriptor `fd` of process.
+pub fn pid_fd_vnode_path(pid: Pid, fd: i32) -> Result<vnode_fdinfowithpath> {
+    let mut info = mem::MaybeUninit::<vnode_fdinfowithpath>::uninit();
+    let size = mem::size_of::<vnode_fdinfowithpath>() as libc::c_int;
+
+    let result = unsafe {
+        proc_pidfdinfo(
+            pid,
+            fd,
+            PROC_PIDFDVNODEPATHINFO,
+            info.as_mut_ptr() as *mut libc::c_void,
+            size,
+        )
+    };
+
+    if result <= 0 {
+        Err(Error::last_os_error().with_ffi("proc_pidfdinfo"))
+    } else {
+        unsafe { Ok(info.assume_init()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::{proc_fileinfo, vnode_fdinfowithpath, vnode_info_path};
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(mem::size_of::<proc_fileinfo>(), 24);
+        assert_eq!(mem::align_of::<proc_fileinfo>(), 8);
+
+        assert_eq!(mem::size_of::<vnode_info_path>(), 1176);
+
+        assert_eq!(mem::size_of::<vnode_fdinfowithpath>(), 1200);
+        assert_eq!(mem::align_of::<vnode_fdinfowithpath>(), 8);
+    }
+}
@@ -1,5 +1,7 @@
+mod fd;
 mod proc_args;
 mod process;
 
+pub use self::fd::*;
 pub use self::proc_args::*;
 pub use self::process::*;
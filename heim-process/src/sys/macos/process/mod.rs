@@ -12,20 +12,27 @@ use heim_common::sys::IntoTime;
 use heim_common::units::Time;
 
 use super::{bindings, pids, utils::catch_zombie};
-use crate::os::unix::Signal;
-use crate::sys::common::UniqueId;
-use crate::sys::unix::pid_kill;
+use crate::os::unix::{Gids, Signal, Uids};
+use crate::sys::common::{load_attribute, UniqueId};
+use crate::sys::unix::{pgid_kill, pid_kill, pid_set_nice, pid_try_wait, terminal, username};
 pub use crate::sys::unix::{Environment, EnvironmentIter, IntoEnvironmentIter};
-use crate::{Pid, ProcessError, ProcessResult, Status};
+use crate::{Attribute, ExitStatus, Pid, ProcessError, ProcessInfo, ProcessResult, Status};
 
 mod command;
 mod cpu_times;
 mod env;
 mod memory;
+mod open_files;
+mod thread;
 
 pub use self::command::{Command, CommandIter};
 pub use self::cpu_times::CpuTime;
 pub use self::memory::Memory;
+pub use self::open_files::OpenFile;
+pub use self::thread::Thread;
+
+// Declared at `sys/types.h`, used for the processes without controlling terminal
+const NODEV: libc::dev_t = -1;
 
 #[derive(Debug)]
 pub struct Process {
@@ -94,6 +101,10 @@ impl Process {
         Ok(self.unique_id.create_time())
     }
 
+    pub fn unique_id(&self) -> &UniqueId {
+        &self.unique_id
+    }
+
     pub async fn cpu_time(&self) -> ProcessResult<CpuTime> {
         match darwin_libproc::task_info(self.pid) {
             Ok(task_info) => Ok(CpuTime::from(task_info)),
@@ -114,6 +125,147 @@ impl Process {
         }
     }
 
+    pub fn open_files(&self) -> impl Stream<Item = ProcessResult<OpenFile>> {
+        match open_files::open_files(self.pid) {
+            Ok(files) => stream::iter(files.into_iter().map(Ok)).left_stream(),
+            Err(e) => stream::once(future::err(e)).right_stream(),
+        }
+    }
+
+    pub async fn num_fds(&self) -> ProcessResult<usize> {
+        open_files::num_fds(self.pid)
+    }
+
+    pub fn threads(&self) -> impl Stream<Item = ProcessResult<Thread>> {
+        // TODO: Stub
+        stream::iter(vec![])
+    }
+
+    pub async fn num_threads(&self) -> ProcessResult<u64> {
+        match darwin_libproc::task_info(self.pid) {
+            Ok(task_info) => Ok(task_info.pti_threadnum as u64),
+            Err(ref e) if e.kind() == io::ErrorKind::PermissionDenied => {
+                Err(ProcessError::AccessDenied(self.pid))
+            }
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn username(&self) -> ProcessResult<String> {
+        let uids = self.uids().await?;
+
+        username(uids.real()).await
+    }
+
+    // `kinfo_proc` is used instead of `proc_bsdinfo` here,
+    // as it is available for the other users processes also
+    pub async fn uids(&self) -> ProcessResult<Uids> {
+        match bindings::process(self.pid) {
+            Ok(kinfo_proc) => Ok(Uids {
+                real: kinfo_proc.kp_eproc.e_pcred.p_ruid,
+                effective: kinfo_proc.kp_eproc.e_ucred.cr_uid,
+                saved: kinfo_proc.kp_eproc.e_pcred.p_svuid,
+            }),
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn gids(&self) -> ProcessResult<Gids> {
+        match bindings::process(self.pid) {
+            Ok(kinfo_proc) => Ok(Gids {
+                real: kinfo_proc.kp_eproc.e_pcred.p_rgid,
+                // Effective group ID is the first one in the credentials groups list
+                effective: kinfo_proc.kp_eproc.e_ucred.cr_groups[0],
+                saved: kinfo_proc.kp_eproc.e_pcred.p_svgid,
+            }),
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn groups(&self) -> ProcessResult<Vec<u32>> {
+        match bindings::process(self.pid) {
+            Ok(kinfo_proc) => {
+                let ucred = kinfo_proc.kp_eproc.e_ucred;
+                let amount = ucred.cr_ngroups as usize;
+
+                Ok(ucred.cr_groups.iter().take(amount).cloned().collect())
+            }
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn nice(&self) -> ProcessResult<i32> {
+        match bindings::process(self.pid) {
+            Ok(kinfo_proc) => Ok(i32::from(kinfo_proc.kp_proc.p_nice)),
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn set_nice(&self, value: i32) -> ProcessResult<()> {
+        if self.is_running().await? {
+            pid_set_nice(self.pid, value)
+        } else {
+            Err(ProcessError::NoSuchProcess(self.pid))
+        }
+    }
+
+    pub async fn terminal(&self) -> ProcessResult<Option<PathBuf>> {
+        match bindings::process(self.pid) {
+            Ok(kinfo_proc) if kinfo_proc.kp_eproc.e_tdev == NODEV => Ok(None),
+            Ok(kinfo_proc) => terminal(kinfo_proc.kp_eproc.e_tdev as u64).await,
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn pgid(&self) -> ProcessResult<Pid> {
+        match bindings::process(self.pid) {
+            Ok(kinfo_proc) => Ok(kinfo_proc.kp_eproc.e_pgid),
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn sid(&self) -> ProcessResult<Pid> {
+        let result = unsafe { libc::getsid(self.pid) };
+
+        if result >= 0 {
+            Ok(result)
+        } else {
+            let e = io::Error::last_os_error();
+            match e.raw_os_error() {
+                Some(libc::ESRCH) => Err(ProcessError::NoSuchProcess(self.pid)),
+                Some(libc::EPERM) => Err(ProcessError::AccessDenied(self.pid)),
+                _ => Err(e.into()),
+            }
+        }
+    }
+
+    pub async fn tpgid(&self) -> ProcessResult<Option<Pid>> {
+        match bindings::process(self.pid) {
+            Ok(kinfo_proc) if kinfo_proc.kp_eproc.e_tdev == NODEV => Ok(None),
+            Ok(kinfo_proc) => Ok(Some(kinfo_proc.kp_eproc.e_tpgid)),
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn signal_group(&self, signal: Signal) -> ProcessResult<()> {
+        if self.is_running().await? {
+            let pgid = self.pgid().await?;
+
+            pgid_kill(pgid, signal)
+        } else {
+            Err(ProcessError::NoSuchProcess(self.pid))
+        }
+    }
+
+    pub async fn snapshot(&self, attributes: &[Attribute]) -> ProcessResult<ProcessInfo> {
+        let mut info = ProcessInfo::new(self.pid);
+        for &attribute in attributes {
+            load_attribute(self, attribute, &mut info).await?;
+        }
+
+        Ok(info)
+    }
+
     pub async fn is_running(&self) -> ProcessResult<bool> {
         let other = get(self.pid).await?;
 
@@ -149,6 +301,16 @@ impl Process {
     pub async fn kill(&self) -> ProcessResult<()> {
         self._signal(Signal::Kill).await
     }
+
+    pub async fn try_wait(&self) -> ProcessResult<Option<ExitStatus>> {
+        // Checking first if process is still running, as the PID could be re-used
+        // by some other child of the current process, which should not be reaped.
+        match self.is_running().await {
+            Ok(true) => pid_try_wait(self.pid),
+            Ok(false) | Err(ProcessError::NoSuchProcess(..)) => Ok(Some(ExitStatus::default())),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 impl hash::Hash for Process {
@@ -0,0 +1,84 @@
+use std::ffi::CStr;
+use std::path::{Path, PathBuf};
+
+use crate::sys::macos::{bindings, utils::catch_zombie};
+use crate::{Pid, ProcessError, ProcessResult};
+
+#[derive(Debug)]
+pub struct OpenFile {
+    fd: i32,
+    path: PathBuf,
+    position: u64,
+    flags: u32,
+}
+
+impl OpenFile {
+    pub fn fd(&self) -> i32 {
+        self.fd
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+}
+
+pub fn num_fds(pid: Pid) -> ProcessResult<usize> {
+    match bindings::pid_fds(pid) {
+        Ok(fds) => Ok(fds.len()),
+        Err(e) => Err(catch_zombie(e, pid)),
+    }
+}
+
+pub fn open_files(pid: Pid) -> ProcessResult<Vec<OpenFile>> {
+    let fds = match bindings::pid_fds(pid) {
+        Ok(fds) => fds,
+        Err(e) => return Err(catch_zombie(e, pid)),
+    };
+
+    let mut files = Vec::with_capacity(fds.len());
+    for fd in fds {
+        if fd.proc_fdtype != bindings::PROX_FDTYPE_VNODE {
+            continue;
+        }
+
+        let info = match bindings::pid_fd_vnode_path(pid, fd.proc_fd) {
+            Ok(info) => info,
+            // File descriptor might be already closed at this point
+            Err(e) if e.raw_os_error() == Some(libc::EBADF) => continue,
+            Err(e) => match catch_zombie(e, pid) {
+                // Some vnodes (ex. kernel ones) are not accessible
+                ProcessError::Load(..) => continue,
+                other => return Err(other),
+            },
+        };
+
+        // Skipping character devices, directories and other non-regular files
+        if info.pvip.mode() & libc::S_IFMT != libc::S_IFREG {
+            continue;
+        }
+
+        let path = unsafe { CStr::from_ptr(info.pvip.vip_path.as_ptr()) };
+        let path = PathBuf::from(path.to_string_lossy().into_owned());
+        // Unlinked files have no path
+        if !path.is_absolute() {
+            continue;
+        }
+
+        files.push(OpenFile {
+            fd: fd.proc_fd,
+            path,
+            position: info.pfi.fi_offset as u64,
+            flags: info.pfi.fi_openflags,
+        });
+    }
+
+    Ok(files)
+}
@@ -0,0 +1,22 @@
+use crate::{CpuTime, Pid, Status};
+
+#[derive(Debug)]
+pub struct Thread;
+
+impl Thread {
+    pub fn id(&self) -> Pid {
+        unimplemented!()
+    }
+
+    pub fn name(&self) -> &str {
+        unimplemented!()
+    }
+
+    pub fn status(&self) -> Status {
+        unimplemented!()
+    }
+
+    pub fn cpu_time(&self) -> &CpuTime {
+        unimplemented!()
+    }
+}
@@ -1,5 +1,7 @@
 mod common;
 
+pub(crate) use self::common::UniqueId;
+
 #[cfg(unix)]
 mod unix;
 
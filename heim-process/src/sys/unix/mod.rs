@@ -1,11 +1,16 @@
+use std::convert::TryFrom;
 use std::io;
 
 use crate::os::unix::Signal;
-use crate::{Pid, ProcessError, ProcessResult};
+use crate::{ExitStatus, Pid, ProcessError, ProcessResult};
 
 mod env;
+mod terminal;
+mod users;
 
 pub use self::env::{Environment, EnvironmentIter, IntoEnvironmentIter};
+pub use self::terminal::terminal;
+pub use self::users::username;
 
 pub fn pid_exists(pid: Pid) -> bool {
     if pid == 0 {
@@ -40,3 +45,66 @@ pub fn pid_kill(pid: Pid, signal: Signal) -> ProcessResult<()> {
         }
     }
 }
+
+pub fn pgid_kill(pgid: Pid, signal: Signal) -> ProcessResult<()> {
+    let result = unsafe { libc::killpg(pgid, signal.into()) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        let e = io::Error::last_os_error();
+        match e.raw_os_error() {
+            Some(libc::ESRCH) => Err(ProcessError::NoSuchProcess(pgid)),
+            Some(libc::EPERM) => Err(ProcessError::AccessDenied(pgid)),
+            _ => Err(e.into()),
+        }
+    }
+}
+
+pub fn pid_set_nice(pid: Pid, value: i32) -> ProcessResult<()> {
+    // `id_t` is unsigned, but PIDs are always positive here
+    let result = unsafe { libc::setpriority(libc::PRIO_PROCESS, pid as libc::id_t, value) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        let e = io::Error::last_os_error();
+        match e.raw_os_error() {
+            Some(libc::ESRCH) => Err(ProcessError::NoSuchProcess(pid)),
+            Some(libc::EPERM) | Some(libc::EACCES) => Err(ProcessError::AccessDenied(pid)),
+            _ => Err(e.into()),
+        }
+    }
+}
+
+/// Reaps the `pid` process if it had exited and returns its exit status.
+///
+/// Returns `None` if the process is still running or if it is not a child of the current process.
+pub fn pid_try_wait(pid: Pid) -> ProcessResult<Option<ExitStatus>> {
+    let mut status: libc::c_int = 0;
+    let result = unsafe { libc::waitpid(pid, &mut status, libc::WNOHANG) };
+
+    if result == 0 {
+        return Ok(None);
+    } else if result < 0 {
+        let e = io::Error::last_os_error();
+        return match e.raw_os_error() {
+            Some(libc::ECHILD) | Some(libc::EINTR) => Ok(None),
+            _ => Err(e.into()),
+        };
+    }
+
+    if libc::WIFEXITED(status) {
+        Ok(Some(ExitStatus {
+            code: Some(libc::WEXITSTATUS(status)),
+            signal: None,
+        }))
+    } else if libc::WIFSIGNALED(status) {
+        Ok(Some(ExitStatus {
+            code: None,
+            signal: Signal::try_from(libc::WTERMSIG(status)).ok(),
+        }))
+    } else {
+        Ok(None)
+    }
+}
@@ -0,0 +1,50 @@
+use std::fs;
+use std::io;
+use std::os::unix::fs::{FileTypeExt, MetadataExt};
+use std::path::{Path, PathBuf};
+
+use heim_runtime as rt;
+
+use crate::ProcessResult;
+
+/// Looks for the character device with the `rdev` number
+/// among the `dir` entries which names are starting with `prefix`.
+fn find_device(dir: &Path, prefix: &str, rdev: u64) -> io::Result<Option<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    for entry in entries {
+        let entry = entry?;
+        if !entry.file_name().to_string_lossy().starts_with(prefix) {
+            continue;
+        }
+
+        // Pseudo-terminals might be gone already while we are iterating
+        let metadata = match fs::metadata(entry.path()) {
+            Ok(metadata) => metadata,
+            Err(..) => continue,
+        };
+
+        if metadata.file_type().is_char_device() && metadata.rdev() == rdev {
+            return Ok(Some(entry.path()));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Resolves the terminal device number into the device path.
+pub async fn terminal(rdev: u64) -> ProcessResult<Option<PathBuf>> {
+    let path =
+        rt::task::spawn_blocking(move || match find_device(Path::new("/dev"), "tty", rdev)? {
+            Some(path) => Ok(Some(path)),
+            None => find_device(Path::new("/dev/pts"), "", rdev),
+        })
+        .await
+        .map_err(io::Error::from)??;
+
+    Ok(path)
+}
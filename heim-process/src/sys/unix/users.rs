@@ -0,0 +1,57 @@
+use std::ffi::CStr;
+use std::io;
+use std::mem;
+use std::ptr;
+
+use heim_runtime as rt;
+
+use crate::ProcessResult;
+
+/// Fallback buffer size, if `sysconf(_SC_GETPW_R_SIZE_MAX)` is not supported.
+const PWD_BUFFER_SIZE: usize = 1024;
+
+fn getpwuid_name(uid: libc::uid_t) -> io::Result<Option<String>> {
+    let size = unsafe { libc::sysconf(libc::_SC_GETPW_R_SIZE_MAX) };
+    let mut buffer = Vec::<libc::c_char>::with_capacity(if size > 0 {
+        size as usize
+    } else {
+        PWD_BUFFER_SIZE
+    });
+
+    loop {
+        let mut passwd = mem::MaybeUninit::<libc::passwd>::uninit();
+        let mut result: *mut libc::passwd = ptr::null_mut();
+        let code = unsafe {
+            libc::getpwuid_r(
+                uid,
+                passwd.as_mut_ptr(),
+                buffer.as_mut_ptr(),
+                buffer.capacity(),
+                &mut result,
+            )
+        };
+
+        match code {
+            0 if result.is_null() => return Ok(None),
+            0 => {
+                let name = unsafe { CStr::from_ptr((*result).pw_name) };
+
+                return Ok(Some(name.to_string_lossy().into_owned()));
+            }
+            // Buffer is too small, retrying with a bigger one
+            libc::ERANGE => buffer.reserve(buffer.capacity() * 2),
+            code => return Err(io::Error::from_raw_os_error(code)),
+        }
+    }
+}
+
+/// Resolves user name for the `uid` given.
+///
+/// If there is no user with such `uid`, its string representation is returned.
+pub async fn username(uid: libc::uid_t) -> ProcessResult<String> {
+    let name = rt::task::spawn_blocking(move || getpwuid_name(uid))
+        .await
+        .map_err(io::Error::from)??;
+
+    Ok(name.unwrap_or_else(|| uid.to_string()))
+}
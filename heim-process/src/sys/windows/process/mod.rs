@@ -1,6 +1,7 @@
 use std::cmp;
 use std::ffi::OsString;
 use std::hash;
+use std::io;
 use std::os::windows::ffi::OsStringExt;
 use std::path::PathBuf;
 
@@ -9,20 +10,24 @@ use heim_common::units::Time;
 use winapi::um::processthreadsapi;
 
 use super::{bindings, pid_exists, pids};
-use crate::sys::common::UniqueId;
-use crate::{Pid, ProcessError, ProcessResult, Status};
+use crate::sys::common::{load_attribute, UniqueId};
+use crate::{Attribute, ExitStatus, Pid, ProcessError, ProcessInfo, ProcessResult, Status};
 
 mod command;
 mod cpu_times;
 mod create_time;
 mod env;
 mod memory;
+mod open_files;
 mod suspend;
+mod thread;
 
 pub use self::command::{Command, CommandIter};
 pub use self::cpu_times::CpuTime;
 pub use self::env::{Environment, EnvironmentIter, IntoEnvironmentIter};
 pub use self::memory::Memory;
+pub use self::open_files::OpenFile;
+pub use self::thread::Thread;
 
 #[derive(Debug)]
 pub struct Process {
@@ -120,6 +125,10 @@ impl Process {
         Ok(self.unique_id.create_time())
     }
 
+    pub fn unique_id(&self) -> &UniqueId {
+        &self.unique_id
+    }
+
     pub async fn cpu_time(&self) -> ProcessResult<CpuTime> {
         // TODO: Move that check into the `bindings::ProcessHandle`
         if self.pid == 0 {
@@ -142,6 +151,48 @@ impl Process {
         }
     }
 
+    pub fn open_files(&self) -> impl Stream<Item = ProcessResult<OpenFile>> {
+        // TODO: Stub
+        stream::iter(vec![])
+    }
+
+    pub async fn num_fds(&self) -> ProcessResult<usize> {
+        Err(not_implemented("Process::num_fds"))
+    }
+
+    pub fn threads(&self) -> impl Stream<Item = ProcessResult<Thread>> {
+        // TODO: Stub
+        stream::iter(vec![])
+    }
+
+    pub async fn num_threads(&self) -> ProcessResult<u64> {
+        Err(not_implemented("Process::num_threads"))
+    }
+
+    pub async fn username(&self) -> ProcessResult<String> {
+        Err(not_implemented("Process::username"))
+    }
+
+    pub async fn snapshot(&self, attributes: &[Attribute]) -> ProcessResult<ProcessInfo> {
+        let mut info = ProcessInfo::new(self.pid);
+        for &attribute in attributes {
+            // Errors for the attributes which are not implemented yet
+            // are stored in the fields instead of failing the whole snapshot
+            match attribute {
+                Attribute::Cwd => info.cwd = Some(Err(not_implemented("Process::cwd"))),
+                Attribute::Username => {
+                    info.username = Some(Err(not_implemented("Process::username")))
+                }
+                Attribute::NumThreads => {
+                    info.num_threads = Some(Err(not_implemented("Process::num_threads")))
+                }
+                _ => load_attribute(self, attribute, &mut info).await?,
+            }
+        }
+
+        Ok(info)
+    }
+
     pub async fn is_running(&self) -> ProcessResult<bool> {
         let other = get(self.pid).await?;
 
@@ -169,6 +220,14 @@ impl Process {
 
         handle.terminate().map_err(Into::into)
     }
+
+    pub async fn try_wait(&self) -> ProcessResult<Option<ExitStatus>> {
+        match self.is_running().await {
+            Ok(true) => Ok(None),
+            Ok(false) | Err(ProcessError::NoSuchProcess(..)) => Ok(Some(ExitStatus::default())),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 impl hash::Hash for Process {
@@ -185,6 +244,14 @@ impl cmp::PartialEq for Process {
 
 impl cmp::Eq for Process {}
 
+/// Error returned by the methods which are not implemented for Windows yet.
+fn not_implemented(method: &'static str) -> ProcessError {
+    let e = Error::from(io::Error::from(io::ErrorKind::Other))
+        .with_message(format!("{} is not implemented for Windows yet", method));
+
+    ProcessError::from(e)
+}
+
 /// Create the `Process` from `pid` without checking first if pid is alive.
 async fn get_unchecked(pid: Pid) -> ProcessResult<Process> {
     let create_time = self::create_time::get(pid).await?;
@@ -0,0 +1,22 @@
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct OpenFile;
+
+impl OpenFile {
+    pub fn fd(&self) -> i32 {
+        unimplemented!()
+    }
+
+    pub fn path(&self) -> &Path {
+        unimplemented!()
+    }
+
+    pub fn position(&self) -> u64 {
+        unimplemented!()
+    }
+
+    pub fn flags(&self) -> u32 {
+        unimplemented!()
+    }
+}
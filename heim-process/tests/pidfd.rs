@@ -0,0 +1,54 @@
+#![cfg(target_os = "linux")]
+
+// `use_pidfd` switches the process-global flag,
+// so this test is kept in a separate binary in order not to affect other tests.
+
+use std::process::{Child, Command, Stdio};
+
+use heim_process as process;
+use heim_process::os::unix::{ExitStatusExt, Signal};
+
+/// Spawns the `yes` command, `None` is returned if it is not available.
+fn spawn_yes() -> Option<Child> {
+    let yes_path = match which::which("yes") {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!(
+                "Unable to find `yes` command, pidfd test will be skipped: {:?}",
+                e
+            );
+            return None;
+        }
+    };
+
+    let child = Command::new(yes_path)
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    Some(child)
+}
+
+#[heim_derive::test]
+async fn test_pidfd() {
+    process::os::linux::use_pidfd(true);
+
+    let child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(child.id() as process::Pid).await.unwrap();
+    assert!(process.is_running().await.unwrap());
+    assert_eq!(process.try_wait().await.unwrap(), None);
+
+    process.kill().await.unwrap();
+    let status = process.wait(None).await.unwrap().unwrap();
+    assert_eq!(status.signal(), Some(Signal::Kill));
+
+    assert!(!process.is_running().await.unwrap());
+    match process.kill().await {
+        Err(process::ProcessError::NoSuchProcess(..)) => {}
+        other => panic!("Reaped process was signalled: {:?}", other),
+    }
+}
@@ -1,8 +1,10 @@
 #![cfg(unix)]
 
-use std::process::{Command, Stdio};
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
 
 use heim_process as process;
+use heim_process::os::unix::{ExitStatusExt, Signal};
 
 #[heim_derive::test]
 async fn test_kill() {
@@ -37,3 +39,74 @@ async fn test_kill() {
         Err(e) => panic!("Process::signal failed to kill the test process: {:#?}", e),
     }
 }
+
+/// Spawns the `yes` command, `None` is returned if it is not available.
+fn spawn_yes() -> Option<Child> {
+    let yes_path = match which::which("yes") {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!(
+                "Unable to find `yes` command, signals test will be skipped: {:?}",
+                e
+            );
+            return None;
+        }
+    };
+
+    let child = Command::new(yes_path)
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    Some(child)
+}
+
+#[heim_derive::test]
+async fn test_wait() {
+    let child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(child.id() as process::Pid).await.unwrap();
+    let status = process.wait(Some(Duration::from_millis(50))).await.unwrap();
+    assert!(
+        status.is_none(),
+        "Process exited unexpectedly: {:?}",
+        status
+    );
+
+    process.terminate().await.unwrap();
+    let status = process.wait(None).await.unwrap().unwrap();
+    assert_eq!(status.code(), None);
+    assert_eq!(status.signal(), Some(Signal::Term));
+
+    assert!(!process.is_running().await.unwrap_or(false));
+}
+
+#[heim_derive::test]
+async fn test_wait_procs() {
+    let children = match (spawn_yes(), spawn_yes()) {
+        (Some(first), Some(second)) => vec![first, second],
+        _ => return,
+    };
+
+    let mut processes = Vec::new();
+    for child in &children {
+        processes.push(process::get(child.id() as process::Pid).await.unwrap());
+    }
+    processes[0].kill().await.unwrap();
+
+    let (gone, alive) = process::wait_procs(processes, Some(Duration::from_secs(1)))
+        .await
+        .unwrap();
+    assert_eq!(gone.len(), 1);
+    assert_eq!(gone[0].0.pid(), children[0].id() as process::Pid);
+    assert_eq!(gone[0].1.signal(), Some(Signal::Kill));
+    assert_eq!(alive.len(), 1);
+
+    alive[0].kill().await.unwrap();
+    let (gone, alive) = process::wait_procs(alive, None).await.unwrap();
+    assert_eq!(gone.len(), 1);
+    assert!(alive.is_empty());
+}
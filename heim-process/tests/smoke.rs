@@ -66,13 +66,338 @@ async fn smoke_processes() {
         try_method!(process.cpu_usage());
         try_method!(process.memory());
         try_method!(process.is_running());
+        #[cfg(any(target_os = "linux", target_os = "macos"))] // Not implemented yet for all platforms
+        try_method!(process.num_fds());
+        try_method!(process.open_files().try_for_each(|_| future::ok(())));
+        #[cfg(any(target_os = "linux", target_os = "macos"))] // Not implemented yet for all platforms
+        try_method!(process.num_threads());
+        try_method!(process.threads().try_for_each(|_| future::ok(())));
+        #[cfg(not(target_os = "windows"))] // Not implemented yet
+        try_method!(process.username());
+
+        #[cfg(unix)]
+        {
+            use heim_process::os::unix::ProcessExt;
+
+            try_method!(process.uids());
+            try_method!(process.gids());
+            try_method!(process.groups());
+            try_method!(process.nice());
+            try_method!(process.terminal());
+            try_method!(process.pgid());
+            try_method!(process.sid());
+            try_method!(process.tpgid());
+        }
 
         #[cfg(target_os = "linux")]
         {
             use heim_process::os::linux::ProcessExt;
 
             try_method!(process.io_counters());
+            try_method!(process.full_memory());
+            try_method!(process.memory_maps().try_for_each(|_| future::ok(())));
+            try_method!(process.ctx_switches());
+            try_method!(process.page_faults());
+            try_method!(process.priority());
+            try_method!(process.rt_priority());
+            try_method!(process.processor());
+            try_method!(process.blkio_delay());
+            try_method!(process.cpu_affinity());
+            try_method!(process.sched_policy());
+            try_method!(process.sched_priority());
+            try_method!(process.io_priority());
+            try_method!(process.rlimit(process::os::linux::Resource::NoFile));
             try_method!(process.net_io_counters().try_for_each(|_| future::ok(())));
+            try_method!(process
+                .connections(heim_net::ConnectionKind::All)
+                .try_for_each(|_| future::ok(())));
         }
     }
 }
+
+#[heim_derive::test]
+async fn smoke_tree() {
+    let tree = process::tree().await.unwrap();
+
+    for pid in tree.roots() {
+        let _ = tree.get(pid);
+        let _ = tree.children(pid);
+        let _ = tree.descendants(pid);
+    }
+
+    let current = process::current().await.unwrap();
+    try_method!(current.children(true));
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_open_files() {
+    let manifest = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let _file = std::fs::File::open(&manifest).unwrap();
+    let _null = std::fs::File::open("/dev/null").unwrap();
+
+    let current = process::current().await.unwrap();
+    let paths = current
+        .open_files()
+        .map_ok(|file| file.path().to_path_buf())
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    assert!(paths.contains(&manifest.canonicalize().unwrap()));
+    assert!(!paths.iter().any(|path| path.starts_with("/dev")));
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_cpu_affinity() {
+    use heim_process::os::linux::ProcessExt;
+
+    let current = process::current().await.unwrap();
+    let cpus = current.cpu_affinity().await.unwrap();
+    assert!(!cpus.is_empty());
+
+    // Setting the same affinity back should not change anything
+    current.set_cpu_affinity(&cpus).await.unwrap();
+    assert_eq!(current.cpu_affinity().await.unwrap(), cpus);
+}
+
+#[heim_derive::test]
+#[cfg(unix)]
+async fn smoke_nice() {
+    use heim_process::os::unix::ProcessExt;
+
+    let current = process::current().await.unwrap();
+    let nice = current.nice().await.unwrap();
+    assert!((-20..=19).contains(&nice));
+
+    current.set_nice(nice).await.unwrap();
+    assert_eq!(current.nice().await.unwrap(), nice);
+}
+
+#[heim_derive::test]
+async fn smoke_snapshot() {
+    use heim_process::Attribute;
+
+    let current = process::current().await.unwrap();
+    let info = current
+        .snapshot(&[Attribute::Name, Attribute::Status, Attribute::CreateTime])
+        .await
+        .unwrap();
+
+    assert_eq!(info.pid(), current.pid());
+    assert_eq!(
+        info.name().unwrap().as_ref().unwrap(),
+        &current.name().await.unwrap()
+    );
+    assert!(info.status().unwrap().is_ok());
+    assert!(info.create_time().unwrap().is_ok());
+    assert!(info.exe().is_none());
+}
+
+#[heim_derive::test]
+async fn smoke_snapshot_name_and_command() {
+    use heim_process::Attribute;
+
+    // Test binary name is long enough to be truncated in the `/proc/<pid>/stat`
+    let current = process::current().await.unwrap();
+    let info = current
+        .snapshot(&[Attribute::Command, Attribute::Name])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        info.name().unwrap().as_ref().unwrap(),
+        &current.name().await.unwrap()
+    );
+    assert!(info.command().unwrap().is_ok());
+}
+
+#[heim_derive::test]
+async fn smoke_processes_with() {
+    use heim_process::Attribute;
+
+    let processes = process::processes_with(&[
+        Attribute::ParentPid,
+        Attribute::Name,
+        Attribute::Exe,
+        Attribute::Command,
+        Attribute::Status,
+        Attribute::CreateTime,
+        Attribute::CpuTime,
+        Attribute::Memory,
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        // Not implemented yet for all platforms
+        Attribute::NumThreads,
+    ]);
+    rt::pin!(processes);
+
+    while let Some(process) = processes.next().await {
+        let (process, info) = match process {
+            Ok(process) => process,
+            e @ Err(ProcessError::Load(..)) => panic!("{:#?}", e),
+            _ => continue,
+        };
+
+        assert_eq!(process.pid(), info.pid());
+        assert!(info.name().is_some());
+        assert!(info.cwd().is_none());
+    }
+}
+
+#[heim_derive::test]
+async fn smoke_cpu_usage_tracker() {
+    let mut tracker = process::CpuUsageTracker::new();
+    let current = process::current().await.unwrap();
+
+    assert!(tracker.cpu_usage(&current).await.unwrap().is_none());
+    assert!(tracker.cpu_usage(&current).await.unwrap().is_some());
+    assert_eq!(tracker.len(), 1);
+
+    tracker.prune();
+    assert_eq!(tracker.len(), 1);
+    tracker.prune();
+    assert!(tracker.is_empty());
+}
+
+#[heim_derive::test]
+async fn smoke_find() {
+    let current = process::current().await.unwrap();
+    let name = current.name().await.unwrap();
+    let parent_pid = current.parent_pid().await.unwrap();
+
+    let processes = process::find()
+        .name(name)
+        .parent_pid(parent_pid)
+        .processes()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert!(processes.contains(&current));
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let processes = process::find()
+            .parent_pid(parent_pid)
+            .username(current.username().await.unwrap())
+            .processes()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert!(processes.contains(&current));
+    }
+
+    // Username filter is not implemented yet and should not match anything
+    #[cfg(target_os = "windows")]
+    {
+        let processes = process::find()
+            .parent_pid(parent_pid)
+            .username("SYSTEM")
+            .processes()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert!(processes.is_empty());
+    }
+
+    let processes = process::find()
+        .parent_pid(current.pid())
+        .status(process::Status::Dead)
+        .processes()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert!(processes.is_empty());
+}
+
+#[heim_derive::test]
+#[cfg(feature = "regex")]
+async fn smoke_find_regex() {
+    let current = process::current().await.unwrap();
+    let name = current.name().await.unwrap();
+    let regex = regex::Regex::new(&format!("^{}$", regex::escape(&name))).unwrap();
+
+    let processes = process::find()
+        .name_regex(regex)
+        .processes()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert!(processes.contains(&current));
+}
+
+#[heim_derive::test]
+#[cfg(unix)]
+async fn smoke_process_group() {
+    use heim_process::os::unix::ProcessExt;
+
+    let current = process::current().await.unwrap();
+    assert!(current.pgid().await.unwrap() > 0);
+    assert!(current.sid().await.unwrap() > 0);
+
+    // Tests might be executed without controlling terminal
+    if let Some(path) = current.terminal().await.unwrap() {
+        assert!(path.exists());
+        assert!(current.tpgid().await.unwrap().is_some());
+    }
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_scheduling() {
+    use heim_process::os::linux::ProcessExt;
+
+    let current = process::current().await.unwrap();
+
+    let policy = current.sched_policy().await.unwrap();
+    let priority = current.sched_priority().await.unwrap();
+    current.set_sched_policy(policy, priority).await.unwrap();
+    assert_eq!(current.sched_policy().await.unwrap(), policy);
+
+    // `SCHED_DEADLINE` is read-only
+    match current
+        .set_sched_policy(process::os::linux::SchedPolicy::Deadline, 0)
+        .await
+    {
+        Err(ProcessError::Load(e)) => {
+            assert_eq!(e.as_inner().kind(), std::io::ErrorKind::InvalidInput)
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+
+    let io_priority = current.io_priority().await.unwrap();
+    current.set_io_priority(io_priority).await.unwrap();
+    assert_eq!(current.io_priority().await.unwrap(), io_priority);
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_stat_counters() {
+    use heim_process::os::linux::ProcessExt;
+
+    let current = process::current().await.unwrap();
+    let ctx_switches = current.ctx_switches().await.unwrap();
+    assert!(ctx_switches.voluntary() + ctx_switches.involuntary() > 0);
+
+    let page_faults = current.page_faults().await.unwrap();
+    assert!(page_faults.minor() > 0);
+
+    let cpu_count = heim_cpu::logical_count().await.unwrap();
+    assert!((current.processor().await.unwrap() as u64) < cpu_count);
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_rlimit() {
+    use heim_process::os::linux::{ProcessExt, Resource};
+
+    let current = process::current().await.unwrap();
+    let limit = current.rlimit(Resource::NoFile).await.unwrap();
+    assert!(limit.soft() <= limit.hard() || limit.hard().is_none());
+
+    current
+        .set_rlimit(Resource::NoFile, limit.soft(), limit.hard())
+        .await
+        .unwrap();
+    assert_eq!(current.rlimit(Resource::NoFile).await.unwrap(), limit);
+}
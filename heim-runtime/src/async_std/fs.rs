@@ -31,6 +31,14 @@ where
     fs::read_link(path).await.map(|path| path.into())
 }
 
+pub async fn metadata<T>(path: T) -> io::Result<fs::Metadata>
+where
+    T: AsRef<Path> + Send,
+{
+    let path = path.as_ref();
+    fs::metadata(path).await
+}
+
 pub async fn read<T>(path: T) -> io::Result<Vec<u8>>
 where
     T: AsRef<Path> + Send,
@@ -7,7 +7,13 @@ use futures_core::Stream;
 use futures_util::{future::ready, StreamExt, TryStreamExt};
 
 use super::runtime;
-pub use runtime::fs::{path_exists, read, read_dir, read_lines, read_link, read_to_string};
+pub use runtime::fs::{
+    metadata, path_exists, read, read_dir, read_lines, read_link, read_to_string,
+};
+
+mod roots;
+
+pub use self::roots::{proc_path, proc_root, set_proc_root, set_sys_root, sys_path, sys_root};
 
 /// Read `path` file as a string and try to convert it into `R`
 pub async fn read_into<T, R, E>(path: T) -> Result<R, E>
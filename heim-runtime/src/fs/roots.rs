@@ -0,0 +1,117 @@
+//! Mount points of the virtual filesystems used by `heim`.
+//!
+//! By default `procfs` is expected to be mounted at `/proc` and `sysfs` at `/sys`,
+//! but it is not always the case; for example, when running inside a container,
+//! host filesystems are usually bind-mounted at some other place.
+//!
+//! Initial values can be overridden with `HEIM_PROC_ROOT` and `HEIM_SYS_ROOT`
+//! environment variables, or at runtime with [`set_proc_root`] and [`set_sys_root`] functions.
+//!
+//! [`set_proc_root`]: ./fn.set_proc_root.html
+//! [`set_sys_root`]: ./fn.set_sys_root.html
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+const PROC_ROOT_ENV: &str = "HEIM_PROC_ROOT";
+const SYS_ROOT_ENV: &str = "HEIM_SYS_ROOT";
+
+fn from_env(key: &str, default: &str) -> PathBuf {
+    match env::var_os(key) {
+        Some(value) if !value.is_empty() => PathBuf::from(value),
+        _ => PathBuf::from(default),
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref PROC_ROOT: RwLock<PathBuf> = RwLock::new(from_env(PROC_ROOT_ENV, "/proc"));
+    static ref SYS_ROOT: RwLock<PathBuf> = RwLock::new(from_env(SYS_ROOT_ENV, "/sys"));
+}
+
+fn get(root: &RwLock<PathBuf>) -> PathBuf {
+    match root.read() {
+        Ok(path) => path.clone(),
+        // Lock can be poisoned only if `set` had panicked,
+        // stored path is still a valid one in that case.
+        Err(e) => e.into_inner().clone(),
+    }
+}
+
+fn set(root: &RwLock<PathBuf>, path: PathBuf) {
+    match root.write() {
+        Ok(mut guard) => *guard = path,
+        Err(e) => *e.into_inner() = path,
+    }
+}
+
+/// Returns path to the `procfs` mount point.
+///
+/// Defaults to the `HEIM_PROC_ROOT` environment variable value if it is set,
+/// or to the `/proc` otherwise.
+pub fn proc_root() -> PathBuf {
+    get(&PROC_ROOT)
+}
+
+/// Changes path to the `procfs` mount point used by all `heim` crates.
+pub fn set_proc_root<T>(path: T)
+where
+    T: Into<PathBuf>,
+{
+    set(&PROC_ROOT, path.into())
+}
+
+/// Returns path to the `sysfs` mount point.
+///
+/// Defaults to the `HEIM_SYS_ROOT` environment variable value if it is set,
+/// or to the `/sys` otherwise.
+pub fn sys_root() -> PathBuf {
+    get(&SYS_ROOT)
+}
+
+/// Changes path to the `sysfs` mount point used by all `heim` crates.
+pub fn set_sys_root<T>(path: T)
+where
+    T: Into<PathBuf>,
+{
+    set(&SYS_ROOT, path.into())
+}
+
+/// Resolves `path` relative to the `procfs` mount point.
+///
+/// ```rust
+/// # use std::path::Path;
+/// # use heim_runtime::fs;
+/// fs::set_proc_root("/host/proc");
+/// assert_eq!(fs::proc_path("net/dev"), Path::new("/host/proc/net/dev"));
+/// ```
+pub fn proc_path<T>(path: T) -> PathBuf
+where
+    T: AsRef<Path>,
+{
+    proc_root().join(path)
+}
+
+/// Resolves `path` relative to the `sysfs` mount point.
+pub fn sys_path<T>(path: T) -> PathBuf
+where
+    T: AsRef<Path>,
+{
+    sys_root().join(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn test_roots() {
+        set_proc_root("/host/proc");
+        set_sys_root("/host/sys");
+
+        assert_eq!(proc_path("1/stat"), Path::new("/host/proc/1/stat"));
+        assert_eq!(sys_path("class/hwmon"), Path::new("/host/sys/class/hwmon"));
+    }
+}
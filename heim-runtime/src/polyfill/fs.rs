@@ -32,6 +32,14 @@ where
     spawn(move || fs::read_to_string(path)).await
 }
 
+/// Asynchronously query metadata about a file, following symbolic links.
+pub async fn metadata<T>(path: T) -> io::Result<fs::Metadata>
+where
+    T: AsRef<Path> + Send,
+{
+    spawn(move || fs::metadata(path)).await
+}
+
 pub async fn read_link<T>(path: T) -> io::Result<PathBuf>
 where
     T: AsRef<Path> + Send,
@@ -66,8 +66,8 @@ where
 }
 
 async fn detect_openvz() -> Result<Virtualization, ()> {
-    let f1 = rt::fs::path_exists("/proc/vz");
-    let f2 = rt::fs::path_exists("/proc/bc");
+    let f1 = rt::fs::path_exists(rt::fs::proc_path("vz"));
+    let f2 = rt::fs::path_exists(rt::fs::proc_path("bc"));
 
     match rt::join!(f1, f2) {
         // `/proc/vz` exists in container and outside of the container,
@@ -107,11 +107,11 @@ where
 pub async fn detect_container() -> Result<Virtualization, ()> {
     future::err(())
         .or_else(|_| detect_openvz())
-        .or_else(|_| detect_wsl("/proc/sys/kernel/osrelease"))
+        .or_else(|_| detect_wsl(rt::fs::proc_path("sys/kernel/osrelease")))
         .or_else(|_| detect_systemd_container("/run/systemd/container"))
-        .or_else(|_| detect_init_env("/proc/1/environ"))
+        .or_else(|_| detect_init_env(rt::fs::proc_path("1/environ")))
         // TODO: Check for a `/proc/1/environ` if there is `container` env var exists
-        .or_else(|_| detect_cgroups("/proc/self/cgroup"))
+        .or_else(|_| detect_cgroups(rt::fs::proc_path("self/cgroup")))
         .await
 }
 
@@ -14,10 +14,10 @@ use heim_runtime as rt;
 use crate::Virtualization;
 
 #[allow(unused)]
-const DEVICE_TREE_ROOT: &str = "/proc/device-tree";
+const DEVICE_TREE_ROOT: &str = "device-tree";
 
 #[allow(unused)]
-const HYPERVISOR_COMPAT_PATH: &str = "/proc/device-tree/hypervisor/compatible";
+const HYPERVISOR_COMPAT_PATH: &str = "device-tree/hypervisor/compatible";
 
 #[allow(unused)]
 async fn hypervisor<T>(path: T) -> Result<Virtualization, ()>
@@ -57,8 +57,8 @@ where
     target_arch = "powerpc64"
 ))]
 pub async fn detect_vm_device_tree() -> Result<Virtualization, ()> {
-    hypervisor(HYPERVISOR_COMPAT_PATH)
-        .or_else(|_| device_tree(DEVICE_TREE_ROOT))
+    hypervisor(rt::fs::proc_path(HYPERVISOR_COMPAT_PATH))
+        .or_else(|_| device_tree(rt::fs::proc_path(DEVICE_TREE_ROOT)))
         .await
 }
 
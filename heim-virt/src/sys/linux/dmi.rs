@@ -13,14 +13,14 @@ use crate::Virtualization;
 ))]
 pub async fn detect_vm_dmi() -> Result<Virtualization, ()> {
     const PROBE_FILES: [&str; 4] = [
-        "/sys/class/dmi/id/product_name", /* Test this before sys_vendor to detect KVM over QEMU */
-        "/sys/class/dmi/id/sys_vendor",
-        "/sys/class/dmi/id/board_vendor",
-        "/sys/class/dmi/id/bios_vendor",
+        "class/dmi/id/product_name", /* Test this before sys_vendor to detect KVM over QEMU */
+        "class/dmi/id/sys_vendor",
+        "class/dmi/id/board_vendor",
+        "class/dmi/id/bios_vendor",
     ];
 
     for filename in &PROBE_FILES {
-        let line = match rt::fs::read_first_line(filename).await {
+        let line = match rt::fs::read_first_line(rt::fs::sys_path(filename)).await {
             Ok(line) => line,
             Err(..) => continue,
         };
@@ -11,6 +11,7 @@
 //!  * Processes
 //!  * Virtualization (*in progress*)
 //!  * Hardware sensors (*in progress*)
+//!  * Control groups resource accounting (Linux only)
 //!
 //! ## Platform support
 //!
@@ -123,5 +124,10 @@ pub use heim_virt as virt;
 #[cfg_attr(docsrs, doc(cfg(feature = "sensors")))]
 pub use heim_sensors as sensors;
 
+#[cfg(feature = "cgroup")]
+#[doc(inline)]
+#[cfg_attr(docsrs, doc(cfg(feature = "cgroup")))]
+pub use heim_cgroup as cgroup;
+
 pub use heim_common::units;
 pub use heim_common::{Error, Result};